@@ -0,0 +1,201 @@
+//! derive macros of the `esde` crate
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+/// the pattern binding all fields of a struct or variant (as `__field0`, `__field1`, ...) and the bound names
+fn bind_fields(fields: &syn::Fields) -> (TokenStream2, Vec<syn::Ident>) {
+    let names: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect();
+    let members = fields.members();
+    let pattern = quote! { { #(#members: #names),* } };
+    (pattern, names)
+}
+
+/// the types of all fields of a struct or enum
+fn field_types(data: &syn::Data) -> Vec<&syn::Type> {
+    match data {
+        syn::Data::Struct(s) => s.fields.iter().map(|field| &field.ty).collect(),
+        syn::Data::Enum(e) => e
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| &field.ty))
+            .collect(),
+        syn::Data::Union(_) => panic!("unions cannot be serialized"),
+    }
+}
+
+/// the expression constructing a struct or variant at `path` by reading all of its fields, each in a `PathSegment::Field` and then in `variant` (if any)
+fn read_fields(
+    path: TokenStream2,
+    fields: &syn::Fields,
+    variant: Option<&syn::Ident>,
+) -> TokenStream2 {
+    let variant = variant.map(|variant| {
+        let variant = variant.to_string();
+        quote! { .within(::esde::PathSegment::Variant(#variant)) }
+    });
+    let members: Vec<_> = fields.members().collect();
+    let reads = members.iter().map(|member| {
+        let field = match member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        };
+        quote! {
+            ::esde::Sender::auto(sender).map_err(|err| {
+                err.within(::esde::PathSegment::Field(#field))#variant
+            })?
+        }
+    });
+    quote! { #path { #(#members: #reads),* } }
+}
+
+/// derive `Serialize` and `SerializeRef`
+///
+/// The fields are written one after another, enums start with the index of the variant as [`u8`].
+/// `SerializeRef` is implemented if all fields implement `SerializeRef` as well.
+#[proc_macro_derive(Serialize)]
+pub fn serialize_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    if !ast.generics.params.is_empty() {
+        panic!("Serialize cannot be derived on generic types yet");
+    }
+
+    let name = &ast.ident;
+    let types = field_types(&ast.data);
+
+    // the same body works for both impls, as matching on `&self` binds the fields by reference
+    let (body, ref_body, enum_bound) = match &ast.data {
+        syn::Data::Struct(s) => {
+            let (pattern, names) = bind_fields(&s.fields);
+            let body = quote! {
+                let Self #pattern = self;
+                #(receiver.auto(#names)?;)*
+                ::core::result::Result::Ok(())
+            };
+            (body.clone(), body, None)
+        }
+        syn::Data::Enum(e) if e.variants.is_empty() => {
+            // an enum without variants cannot be matched through a reference
+            (quote! { match self {} }, quote! { match *self {} }, None)
+        }
+        syn::Data::Enum(e) => {
+            assert!(e.variants.len() < u8::MAX as usize);
+            let arms = e.variants.iter().enumerate().map(|(id, variant)| {
+                let id = id as u8;
+                let variant_name = &variant.ident;
+                let (pattern, names) = bind_fields(&variant.fields);
+                quote! {
+                    Self::#variant_name #pattern => {
+                        receiver.auto(#id)?;
+                        #(receiver.auto(#names)?;)*
+                    }
+                }
+            });
+            let body = quote! {
+                match self { #(#arms)* }
+                ::core::result::Result::Ok(())
+            };
+            let bound = quote! { u8: ::esde::Serialize<Item>, };
+            (body.clone(), body, Some(bound))
+        }
+        syn::Data::Union(_) => panic!("unions cannot be serialized"),
+    };
+
+    quote! {
+        impl<Item> ::esde::Serialize<Item> for #name
+        where
+            #(#types: ::esde::Serialize<Item>,)*
+            #enum_bound
+        {
+            fn serialize<R: ::esde::Receiver<Item = Item>>(
+                self,
+                receiver: &mut R,
+            ) -> ::core::result::Result<(), R::Error> {
+                #body
+            }
+        }
+
+        impl<Item> ::esde::SerializeRef<Item> for #name
+        where
+            #(#types: ::esde::SerializeRef<Item>,)*
+            #enum_bound
+        {
+            fn serialize_ref<R: ::esde::Receiver<Item = Item>>(
+                &self,
+                receiver: &mut R,
+            ) -> ::core::result::Result<(), R::Error> {
+                #ref_body
+            }
+        }
+    }
+    .into()
+}
+
+/// derive `Deserialize`
+///
+/// The fields are read one after another, enums start with the index of the variant as [`u8`] (an unknown index is a `ParseErrorKind::InvalidDiscriminant` error).
+/// Errors while reading a field are reported within a `PathSegment::Field` (and a `PathSegment::Variant` for enums).
+#[proc_macro_derive(Deserialize)]
+pub fn deserialize_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    if !ast.generics.params.is_empty() {
+        panic!("Deserialize cannot be derived on generic types yet");
+    }
+
+    let name = &ast.ident;
+    let types = field_types(&ast.data);
+
+    let (body, enum_bound) = match &ast.data {
+        syn::Data::Struct(s) => {
+            let make = read_fields(quote! { Self }, &s.fields, None);
+            (quote! { ::core::result::Result::Ok(#make) }, None)
+        }
+        syn::Data::Enum(e) => {
+            assert!(e.variants.len() < u8::MAX as usize);
+            let arms = e.variants.iter().enumerate().map(|(id, variant)| {
+                let id = id as u8;
+                let variant_name = &variant.ident;
+                let make = read_fields(
+                    quote! { Self::#variant_name },
+                    &variant.fields,
+                    Some(variant_name),
+                );
+                quote! { #id => ::core::result::Result::Ok(#make), }
+            });
+            let ty = name.to_string();
+            let body = quote! {
+                let variant: u8 = ::esde::Sender::auto(sender)?;
+                match variant {
+                    #(#arms)*
+                    discriminant => ::core::result::Result::Err(
+                        ::esde::ParseErrorKind::InvalidDiscriminant {
+                            ty: #ty,
+                            discriminant,
+                        }
+                        .into(),
+                    ),
+                }
+            };
+            (body, Some(quote! { u8: ::esde::Deserialize<Item>, }))
+        }
+        syn::Data::Union(_) => panic!("unions cannot be deserialized"),
+    };
+
+    quote! {
+        impl<Item> ::esde::Deserialize<Item> for #name
+        where
+            #(#types: ::esde::Deserialize<Item>,)*
+            #enum_bound
+        {
+            fn deserialize<S: ::esde::Sender<Item = Item> + ?Sized>(
+                sender: &mut S,
+            ) -> ::core::result::Result<Self, ::esde::Error<S::Error>> {
+                #body
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+use crate::{de::cautious_capacity, *};
+
+/// trait for a [`Sender`] of [`u8`]s that can hand out slices of its input without copying, e.g. a [`SliceSender`]
+pub trait BorrowSender<'de>: Sender<Item = u8> {
+    /// get the next `len` bytes as a slice borrowed from the input (throws [`Error::EOF`] if there are not enough)
+    fn borrow_bytes(&mut self, len: usize) -> Result<&'de [u8], Error<Self::Error>>;
+
+    /// automatically parse the (usually inferred) output type that implements [`BorrowDeserialize`]
+    ///
+    /// a parse error that does not yet have an offset gets the [`Sender::position`] before parsing
+    fn auto_borrow<D: BorrowDeserialize<'de>>(&mut self) -> Result<D, Error<Self::Error>> {
+        let position = self.position();
+        D::borrow_deserialize(self).map_err(|err| err.at(position))
+    }
+}
+
+/// trait for an object that can be deserialized from a [`BorrowSender`], possibly borrowing from its input
+///
+/// The layout is the same as the one of the corresponding owned type, e.g. a `&str` can be read wherever a [`String`] was saved.
+/// Owned parts of a value can be read with [`Sender::auto`], as every [`BorrowSender`] is a [`Sender`] as well.
+/// Primitive types and [`String`] implement [`BorrowDeserialize`] by reading just like that, so tuples, arrays, [`Option`]s and [`Vec`]s can mix borrowed and owned elements.
+///
+/// ```rust
+/// # use esde::*;
+/// struct Entry<'de> {
+///     name: &'de str,
+///     size: u64,
+/// }
+///
+/// impl<'de> BorrowDeserialize<'de> for Entry<'de> {
+///     fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+///         sender: &mut S,
+///     ) -> Result<Self, Error<S::Error>> {
+///         Ok(Entry {
+///             name: sender.auto_borrow()?,
+///             size: sender.auto()?,
+///         })
+///     }
+/// }
+///
+/// let buffer = to_vec(("data.bin", 1024u64));
+///
+/// let mut sender = SliceSender::new(&buffer);
+/// let entry: Entry = sender.auto_borrow().unwrap();
+/// assert_eq!(entry.name, "data.bin");
+/// assert_eq!(entry.size, 1024);
+///
+/// let mut sender = SliceSender::new(&buffer);
+/// let tuple: (&str, u64) = sender.auto_borrow().unwrap();
+/// assert_eq!(tuple, ("data.bin", 1024));
+/// ```
+pub trait BorrowDeserialize<'de>: Sized {
+    /// given a sender of type `S`, deserialize [`Self`] from `sender`'s input or throw an error
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>>;
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// implementations
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<'de> BorrowDeserialize<'de> for &'de [u8] {
+    /// reading the length as [`usize`] (checked against [`Limits::max_length`]), then borrowing that many bytes
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let len = sender.auto()?;
+        sender.limits().check_length(len)?;
+        sender.borrow_bytes(len)
+    }
+}
+
+impl<'de> BorrowDeserialize<'de> for &'de str {
+    /// reading just like a [`String`], but borrowing the bytes (throws error if invalid UTF-8)
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let len = sender.auto()?;
+        sender.limits().check_string_length(len)?;
+        let bytes = sender.borrow_bytes(len)?;
+        std::str::from_utf8(bytes).map_err(|err| ParseErrorKind::InvalidUtf8(err).into())
+    }
+}
+
+impl<'de> BorrowDeserialize<'de> for Cow<'de, [u8]> {
+    /// reading as `&[u8]`, always resulting in [`Cow::Borrowed`]
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Cow::Borrowed(sender.auto_borrow()?))
+    }
+}
+
+impl<'de> BorrowDeserialize<'de> for Cow<'de, str> {
+    /// reading as `&str`, always resulting in [`Cow::Borrowed`]
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Cow::Borrowed(sender.auto_borrow()?))
+    }
+}
+
+impl<'de, T> BorrowDeserialize<'de> for Option<T>
+where
+    T: BorrowDeserialize<'de>,
+{
+    /// reading just like an [`Option`] that is not borrowed
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let is_some: bool = sender.auto()?;
+        if is_some {
+            Ok(Some(
+                sender
+                    .auto_borrow()
+                    .map_err(|err| err.within(PathSegment::Variant("Some")))?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'de, T> BorrowDeserialize<'de> for Vec<T>
+where
+    T: BorrowDeserialize<'de>,
+{
+    /// reading just like a [`Vec`] that is not borrowed (only the elements borrow from the input)
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let len = sender.auto()?;
+        sender.limits().check_length(len)?;
+        let mut vector = Vec::with_capacity(cautious_capacity::<T>(len));
+        for index in 0..len {
+            vector.push(
+                sender
+                    .auto_borrow()
+                    .map_err(|err| err.within(PathSegment::Index(index)))?,
+            );
+        }
+        Ok(vector)
+    }
+}
+
+impl<'de, T, const N: usize> BorrowDeserialize<'de> for [T; N]
+where
+    T: BorrowDeserialize<'de>,
+{
+    /// reading just like an array that is not borrowed
+    fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let mut buffer = Vec::with_capacity(N);
+        for index in 0..N {
+            buffer.push(
+                sender
+                    .auto_borrow()
+                    .map_err(|err| err.within(PathSegment::Index(index)))?,
+            );
+        }
+        let Ok(buffer) = buffer.try_into() else {
+            unreachable!()
+        };
+        Ok(buffer)
+    }
+}
+
+/// implements [`BorrowDeserialize`] for tuples of the given element types and indices
+macro_rules! tuple_impls {
+    ($(($($name:ident $index:tt),*))*) => {
+        $(
+            impl<'de, $($name),*> BorrowDeserialize<'de> for ($($name,)*)
+            where
+                $($name: BorrowDeserialize<'de>,)*
+            {
+                /// reading just like a tuple that is not borrowed
+                #[allow(unused_variables)]
+                fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+                    sender: &mut S,
+                ) -> Result<Self, Error<S::Error>> {
+                    Ok(($(
+                        sender
+                            .auto_borrow::<$name>()
+                            .map_err(|err| err.within(PathSegment::Field(stringify!($index))))?,
+                    )*))
+                }
+            }
+        )*
+    };
+}
+
+tuple_impls! {
+    ()
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15)
+}
+
+/// implements [`BorrowDeserialize`] for owned types by reading them with [`Sender::auto`]
+macro_rules! owned_impls {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'de> BorrowDeserialize<'de> for $ty {
+                /// reading just like with [`Deserialize`] (nothing is borrowed)
+                fn borrow_deserialize<S: BorrowSender<'de> + ?Sized>(
+                    sender: &mut S,
+                ) -> Result<Self, Error<S::Error>> {
+                    sender.auto()
+                }
+            }
+        )*
+    };
+}
+
+owned_impls!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, String,
+);
@@ -0,0 +1,156 @@
+use crate::*;
+
+/// how integers (including the lengths of collections) are encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntEncoding {
+    /// fixed width, i.e. as many bytes as the integer type has (the default)
+    #[default]
+    Fixed,
+    /// variable width as [LEB128](https://en.wikipedia.org/wiki/LEB128), signed integers are [ZigZag](https://en.wikipedia.org/wiki/Variable-length_quantity#Zigzag_encoding) encoded first
+    ///
+    /// small values take up less space, e.g. any length below 128 is stored as a single byte.
+    /// [`u8`] and [`i8`] are not affected and always take up exactly one byte.
+    Varint,
+}
+
+/// the byte order of fixed-width integers and floats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    /// most significant byte first (the default)
+    #[default]
+    Big,
+    /// least significant byte first, e.g. to match the in-memory layout of C structs on x86
+    Little,
+}
+
+impl Endian {
+    /// the byte order of the platform this code runs on
+    pub const NATIVE: Self = if cfg!(target_endian = "big") {
+        Self::Big
+    } else {
+        Self::Little
+    };
+
+    /// convert big-endian bytes into this byte order, or bytes in this byte order into big-endian (it's the same operation)
+    pub(crate) fn reorder<const N: usize>(self, mut bytes: [u8; N]) -> [u8; N] {
+        if self == Self::Little {
+            bytes.reverse();
+        }
+        bytes
+    }
+}
+
+/// options for the encoding, carried by a [`Sender`] or [`Receiver`]
+///
+/// Data must be read with the same configuration it was written with.
+/// Wrap a sender or receiver in a [`Configured`] to use a non-default configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Config {
+    /// how integers are encoded
+    pub int_encoding: IntEncoding,
+    /// the byte order of fixed-width integers and floats (does not affect varints)
+    pub endian: Endian,
+}
+
+impl Config {
+    /// the default configuration
+    pub const DEFAULT: Self = Self {
+        int_encoding: IntEncoding::Fixed,
+        endian: Endian::Big,
+    };
+}
+
+/// a [`Sender`] or [`Receiver`] using a custom [`Config`]
+///
+/// ```rust
+/// # use esde::*;
+/// let config = Config {
+///     int_encoding: IntEncoding::Varint,
+///     ..Config::DEFAULT
+/// };
+///
+/// let mut receiver = Configured::new(VecReceiver::new(), config);
+/// receiver.auto(vec![1u32, 2, 300]).unwrap();
+/// let buffer = receiver.into_inner().into_inner();
+/// assert_eq!(buffer, [3, 1, 2, 0xac, 0x02]);
+///
+/// let mut sender = Configured::new(SliceSender::new(&buffer), config);
+/// let vector: Vec<u32> = sender.auto().unwrap();
+/// assert_eq!(vector, [1, 2, 300]);
+/// ```
+#[derive(Debug)]
+pub struct Configured<T> {
+    inner: T,
+    config: Config,
+}
+
+impl<T> Configured<T> {
+    /// wrap `inner` (a sender or receiver), making it use `config`
+    pub fn new(inner: T, config: Config) -> Self {
+        Self { inner, config }
+    }
+
+    /// get back the wrapped sender or receiver
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<S: Sender> Sender for Configured<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
+        self.inner.get()
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
+        self.inner.fill_buffer(buffer)
+    }
+
+    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
+        self.inner.get_buffer()
+    }
+
+    fn limits(&self) -> Limits {
+        self.inner.limits()
+    }
+
+    fn position(&self) -> Option<usize> {
+        self.inner.position()
+    }
+
+    fn check_remaining(&self, len: usize) -> Result<(), Error<Self::Error>> {
+        self.inner.check_remaining(len)
+    }
+
+    fn config(&self) -> Config {
+        self.config
+    }
+}
+
+impl<'de, S: BorrowSender<'de>> BorrowSender<'de> for Configured<S> {
+    fn borrow_bytes(&mut self, len: usize) -> Result<&'de [u8], Error<Self::Error>> {
+        self.inner.borrow_bytes(len)
+    }
+}
+
+impl<R: Receiver> Receiver for Configured<R> {
+    type Item = R::Item;
+    type Error = R::Error;
+
+    fn accept(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.inner.accept(item)
+    }
+
+    fn accept_buffer(&mut self, items: &[Self::Item]) -> Result<(), Self::Error>
+    where
+        Self::Item: Clone,
+    {
+        self.inner.accept_buffer(items)
+    }
+
+    fn config(&self) -> Config {
+        self.config
+    }
+}
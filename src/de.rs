@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::{Config, Limits};
+
 /// an error that can occur during deserialization
 #[derive(Debug)]
 pub enum Error<SenderError: std::error::Error> {
@@ -8,12 +10,220 @@ pub enum Error<SenderError: std::error::Error> {
     /// error within the [`Sender`], e.g. a [`std::io::Error`] if a [`std::fs::File`] was used and an error occured while reading
     Sender(SenderError),
     /// an error while parsing, hinting corrupt data or parsing of another type was wasn't stored
-    Parse(Box<dyn std::error::Error>),
+    Parse(ParseError),
+}
+
+/// a segment of the path to the value that was decoded when a [`ParseError`] occured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// a named field of a struct or variant, or a positional one (e.g. `"0"`) of a tuple
+    Field(&'static str),
+    /// a variant of an enum
+    Variant(&'static str),
+    /// an element of a collection
+    Index(usize),
+}
+
+/// the kind of a [`ParseError`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// the bytes of a string are not valid UTF-8
+    InvalidUtf8(std::str::Utf8Error),
+    /// the stored [`u32`] is not a valid [`char`]
+    InvalidChar(u32),
+    /// the stored discriminant does not belong to any variant of the enum `ty`
+    InvalidDiscriminant {
+        /// name of the enum
+        ty: &'static str,
+        /// the discriminant that was read
+        discriminant: u8,
+    },
+    /// the bytes of a [`std::ffi::CString`] contain a nul byte at the given position
+    InteriorNul(usize),
+    /// the stored [`u8`] is not a valid [`bool`]
+    InvalidBool(u8),
+    /// the stored integer is zero, but the type `ty` (e.g. [`std::num::NonZeroU64`]) does not allow that
+    UnexpectedZero {
+        /// name of the type
+        ty: &'static str,
+    },
+    /// the stored number of nanoseconds is not below one second
+    InvalidNanos(u32),
+    /// the stored point in time cannot be represented by [`std::time::SystemTime`] on this platform
+    TimeOutOfRange,
+    /// the stored length does not fit into a [`usize`] on this platform
+    LengthOverflow(u64),
+    /// the stored integer does not fit into the integer type of `bits` bits
+    IntegerOverflow {
+        /// the number of bits of the integer type being decoded
+        bits: u32,
+    },
+    /// one of the [`crate::Limits`] was exceeded
+    LimitExceeded {
+        /// the name of the limit, e.g. `"max_length"`
+        limit: &'static str,
+        /// the value of the limit
+        max: usize,
+        /// the value that exceeded the limit
+        value: usize,
+    },
+    /// a key of a map (or an element of a set) occurs more than once
+    DuplicateKey,
+    /// there are items left over after the value was read completely, see [`crate::SliceSender::finish`]
+    TrailingItems(usize),
+    /// the value in a frame of a [`crate::FramedReader`] ended before it was complete
+    IncompleteFrame,
+    /// the checksum of the length of a frame of a [`crate::FramedReader`] does not match the length
+    HeaderChecksumMismatch {
+        /// the checksum that was read
+        expected: u32,
+        /// the checksum of the length that was read
+        actual: u32,
+    },
+    /// the checksum of a frame of a [`crate::FramedReader`] does not match its content
+    ChecksumMismatch {
+        /// the checksum that was read
+        expected: u32,
+        /// the checksum of the content that was read
+        actual: u32,
+    },
+    /// any other error, e.g. from a custom [`Deserialize`] implementation
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::InvalidUtf8(err) => write!(f, "invalid UTF-8: {err}"),
+            ParseErrorKind::InvalidChar(x) => write!(
+                f,
+                "cannot parse char from u32 0x{x:x?} (not valid unicode character)"
+            ),
+            ParseErrorKind::InvalidDiscriminant { ty, discriminant } => {
+                write!(f, "invalid discriminant {discriminant} for enum {ty}")
+            }
+            ParseErrorKind::InteriorNul(position) => {
+                write!(f, "nul byte at position {position} of a C string")
+            }
+            ParseErrorKind::InvalidBool(x) => write!(f, "invalid bool {x} (expected 0 or 1)"),
+            ParseErrorKind::UnexpectedZero { ty } => write!(f, "unexpected zero for {ty}"),
+            ParseErrorKind::InvalidNanos(x) => {
+                write!(f, "invalid nanoseconds {x} (expected less than one second)")
+            }
+            ParseErrorKind::TimeOutOfRange => {
+                write!(f, "point in time cannot be represented on this platform")
+            }
+            ParseErrorKind::LengthOverflow(len) => {
+                write!(f, "length {len} does not fit into usize on this platform")
+            }
+            ParseErrorKind::IntegerOverflow { bits } => {
+                write!(f, "integer does not fit into {bits} bits")
+            }
+            ParseErrorKind::LimitExceeded { limit, max, value } => {
+                write!(f, "{value} exceeds the limit {limit} of {max}")
+            }
+            ParseErrorKind::DuplicateKey => write!(f, "duplicate key"),
+            ParseErrorKind::TrailingItems(count) => {
+                write!(f, "{count} trailing items after the value")
+            }
+            ParseErrorKind::IncompleteFrame => write!(f, "frame ended before the value"),
+            ParseErrorKind::HeaderChecksumMismatch { expected, actual } => write!(
+                f,
+                "frame length checksum mismatch (expected 0x{expected:08x}, got 0x{actual:08x})"
+            ),
+            ParseErrorKind::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch (expected 0x{expected:08x}, got 0x{actual:08x})"
+            ),
+            ParseErrorKind::Custom(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// an error while parsing, see [`Error::Parse`]
+///
+/// Besides the [`ParseErrorKind`], it carries the offset (the number of `Item`s read before the value that failed to decode, if the [`Sender`] knows its position)
+/// and the path of fields, variants and indices that lead to that value.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: Option<usize>,
+    path: Vec<PathSegment>,
+}
+
+impl ParseError {
+    /// create an error of the given kind, without offset and path
+    pub fn new(kind: ParseErrorKind) -> Self {
+        Self {
+            kind,
+            offset: None,
+            path: Vec::new(),
+        }
+    }
+
+    /// create an error of kind [`ParseErrorKind::Custom`]
+    pub fn custom(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self::new(ParseErrorKind::Custom(error.into()))
+    }
+
+    /// the kind of error
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    /// the offset of the first item of the value that failed to decode, see [`Sender::position`]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// the path to the value that failed to decode, outermost segment first
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.path.is_empty() {
+            write!(f, " in `")?;
+            for (i, segment) in self.path.iter().enumerate() {
+                match segment {
+                    PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                    PathSegment::Field(name) => write!(f, ".{name}")?,
+                    PathSegment::Variant(name) => write!(f, "::{name}")?,
+                    PathSegment::Index(index) => write!(f, "[{index}]")?,
+                }
+            }
+            write!(f, "`")?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidUtf8(err) => Some(err),
+            ParseErrorKind::Custom(err) => Some(&**err),
+            _ => None,
+        }
+    }
+}
+
+impl<T: std::error::Error> From<ParseErrorKind> for Error<T> {
+    fn from(value: ParseErrorKind) -> Self {
+        Self::Parse(ParseError::new(value))
+    }
 }
 
 impl<T: std::error::Error> From<&str> for Error<T> {
     fn from(value: &str) -> Self {
-        Self::Parse(value.into())
+        Self::Parse(ParseError::custom(value))
     }
 }
 
@@ -31,7 +241,7 @@ impl<SenderError: std::error::Error> Display for Error<SenderError> {
                 f,
                 "the sender (the instance providing items) had an error: {err:?}"
             ),
-            Error::Parse(err) => write!(f, "an error occured while parsing: {err:?}"),
+            Error::Parse(err) => write!(f, "an error occured while parsing: {err}"),
         }
     }
 }
@@ -39,23 +249,99 @@ impl<SenderError: std::error::Error> Display for Error<SenderError> {
 impl<T: std::error::Error> std::error::Error for Error<T> {}
 
 impl<SenderError: std::error::Error> Error<SenderError> {
-    /// make a given error a parse error
+    /// make a given error a parse error (of kind [`ParseErrorKind::Custom`])
     ///
     /// useful when parsing to do something like
     /// ```rust
-    /// let buffer = receiver.auto()?;
+    /// # use esde::*;
+    /// # fn parse<S: Sender<Item = u8>>(sender: &mut S) -> Result<String, Error<S::Error>> {
+    /// let buffer = sender.auto()?;
     /// let str = String::from_utf8(buffer).map_err(Error::make_parse)?;
+    /// # Ok(str)
+    /// # }
+    /// ```
+    pub fn make_parse(err: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Parse(ParseError::custom(err))
+    }
+
+    /// prepend a segment to the path of a parse error (other errors are returned unchanged)
+    ///
+    /// implementations of [`Deserialize`] use this to record which part of a value was being decoded, e.g.
+    /// ```rust
+    /// # use esde::*;
+    /// # fn parse<S: Sender<Item = u8>>(sender: &mut S) -> Result<(u32, char), Error<S::Error>> {
+    /// let id = sender.auto().map_err(|err| err.within(PathSegment::Field("id")))?;
+    /// let letter = sender.auto().map_err(|err| err.within(PathSegment::Field("letter")))?;
+    /// # Ok((id, letter))
+    /// # }
     /// ```
-    pub fn make_parse(err: impl std::error::Error + 'static) -> Self {
-        Self::Parse(Box::new(err))
+    pub fn within(self, segment: PathSegment) -> Self {
+        match self {
+            Error::Parse(mut parse) => {
+                parse.path.insert(0, segment);
+                Error::Parse(parse)
+            }
+            other => other,
+        }
+    }
+
+    /// set the offset of a parse error, unless it already has one
+    pub(crate) fn at(self, offset: Option<usize>) -> Self {
+        match self {
+            Error::Parse(mut parse) => {
+                parse.offset = parse.offset.or(offset);
+                Error::Parse(parse)
+            }
+            other => other,
+        }
+    }
+
+    /// whether this is an [`Error::EOF`]
+    pub fn is_eof(&self) -> bool {
+        matches!(self, Error::EOF)
+    }
+
+    /// whether this is an [`Error::Sender`]
+    pub fn is_sender(&self) -> bool {
+        matches!(self, Error::Sender(_))
+    }
+
+    /// whether this is an [`Error::Parse`]
+    pub fn is_parse(&self) -> bool {
+        matches!(self, Error::Parse(_))
+    }
+
+    /// get the sender error, if this is one
+    pub fn into_sender(self) -> Option<SenderError> {
+        match self {
+            Error::Sender(sender) => Some(sender),
+            _ => None,
+        }
+    }
+
+    /// get the parse error, if this is one
+    pub fn into_parse(self) -> Option<ParseError> {
+        match self {
+            Error::Parse(parse) => Some(parse),
+            _ => None,
+        }
+    }
+
+    /// get a reference to the parse error, if this is one
+    pub fn as_parse(&self) -> Option<&ParseError> {
+        match self {
+            Error::Parse(parse) => Some(parse),
+            _ => None,
+        }
     }
 
     /// unwrap to a sender error only
     ///
     /// useful when in main function that, for example, returns [`std::io::Error`] to only see sender errors
+    /// (though for [`std::io::Error`], simply using `?` is preferable, converting EOF and parse errors to IO errors as well)
     ///
     /// # panics
-    /// this function panics if the contained error is *not* a sender error
+    /// this function panics if the contained error is *not* a sender error, see [`Self::into_sender`] for a non-panicking alternative
     pub fn unwrap_sender(self) -> SenderError {
         match self {
             Error::EOF => panic!("unwrapped a EOF error"),
@@ -81,8 +367,8 @@ pub trait Sender {
     /// The canonical implementation calls [`Self::get`] as often as necessary, throwing the corresponding errors.
     /// This function should be overloaded if a better implementation can be made.
     fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
-        for i in 0..buffer.len() {
-            buffer[i] = self.get()?;
+        for item in buffer {
+            *item = self.get()?;
         }
         Ok(())
     }
@@ -97,17 +383,64 @@ pub trait Sender {
         for _ in 0..N {
             buffer.push(self.get()?);
         }
-        let Ok(buffer) = buffer.try_into() else { unreachable!() };
+        let Ok(buffer) = buffer.try_into() else {
+            unreachable!()
+        };
         Ok(buffer)
     }
 
+    /// the [`Limits`] that length-prefixed data read from this sender must respect
+    ///
+    /// # Note
+    /// The canonical implementation returns [`Limits::NONE`], wrap a sender in [`crate::Limited`] to apply limits.
+    fn limits(&self) -> Limits {
+        Limits::NONE
+    }
+
+    /// the [`Config`] that determines the encoding of items read from this sender
+    ///
+    /// # Note
+    /// The canonical implementation returns [`Config::DEFAULT`], wrap a sender in [`crate::Configured`] to use another configuration.
+    fn config(&self) -> Config {
+        Config::DEFAULT
+    }
+
+    /// the number of items read so far, if known
+    ///
+    /// used as [`ParseError::offset`]
+    ///
+    /// # Note
+    /// The canonical implementation returns `None`.
+    fn position(&self) -> Option<usize> {
+        None
+    }
+
+    /// make sure that at least `len` more items can be read, if that is known in advance
+    ///
+    /// Stored lengths of byte sequences (e.g. of a [`String`] or a `Vec<u8>`) are checked with this before reading any byte.
+    /// Lengths of other collections are not, as their elements may take up any number of items (even none, e.g. [`()`](unit)).
+    ///
+    /// # Note
+    /// The canonical implementation does not check anything.
+    /// Senders that know how many items are left (e.g. [`crate::SliceSender`]) should throw [`Error::EOF`] if there are less than `len`.
+    fn check_remaining(&self, len: usize) -> Result<(), Error<Self::Error>> {
+        let _ = len;
+        Ok(())
+    }
+
     /// automatically parse the (usually inferred) output type that implements [`Deserialize`]
-    fn auto<D: Deserialize<Self::Item> + ?Sized>(&mut self) -> Result<D, Error<Self::Error>> {
-        D::deserialize(self)
+    ///
+    /// a parse error that does not yet have an offset gets the [`Self::position`] before parsing
+    fn auto<D: Deserialize<Self::Item>>(&mut self) -> Result<D, Error<Self::Error>> {
+        let position = self.position();
+        D::deserialize(self).map_err(|err| err.at(position))
     }
 
     /// alias for [`Self::auto`] to be used in cases when a type implements [`Sender`] *and* [`crate::Receiver`]
-    fn auto_de<D: Deserialize<Self::Item> + ?Sized>(&mut self) -> Result<D, Error<Self::Error>> {
+    #[deprecated(
+        note = "use `Sender::auto` instead, `std::io::Read` types are wrapped in `esde::io::IoSender` now"
+    )]
+    fn auto_de<D: Deserialize<Self::Item>>(&mut self) -> Result<D, Error<Self::Error>> {
         self.auto()
     }
 }
@@ -118,4 +451,55 @@ pub trait Deserialize<Item>: Sized {
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>>;
+
+    /// deserialize `len` elements stored one after another (no length stored)
+    ///
+    /// # Note
+    /// The canonical implementation deserializes each element (adding its index to the path of parse errors).
+    /// The vector grows while reading, so a corrupt `len` results in an EOF error rather than a huge allocation.
+    /// This method should be overridden if a better implementation can be made, e.g. [`u8`] uses [`Sender::fill_buffer`].
+    fn deserialize_vec<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+        len: usize,
+    ) -> Result<Vec<Self>, Error<S::Error>> {
+        let mut buffer = Vec::with_capacity(cautious_capacity::<Self>(len));
+        for index in 0..len {
+            buffer.push(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Index(index)))?,
+            );
+        }
+        Ok(buffer)
+    }
+
+    /// deserialize `N` elements stored one after another (no length stored)
+    ///
+    /// # Note
+    /// The canonical implementation deserializes each element (adding its index to the path of parse errors).
+    /// This method should be overridden if a better implementation can be made, e.g. [`u8`] uses [`Sender::get_buffer`].
+    fn deserialize_array<const N: usize, S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<[Self; N], Error<S::Error>> {
+        let mut buffer = Vec::with_capacity(N);
+        for index in 0..N {
+            buffer.push(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Index(index)))?,
+            );
+        }
+        let Ok(buffer) = buffer.try_into() else {
+            unreachable!()
+        };
+        Ok(buffer)
+    }
+}
+
+/// the number of bytes that may be allocated up front for a collection of stored (i.e. untrusted) length
+pub(crate) const MAX_PREALLOCATION: usize = 1 << 20;
+
+/// the capacity to reserve up front for `len` elements of type `T`, capped at [`MAX_PREALLOCATION`] bytes
+pub(crate) fn cautious_capacity<T>(len: usize) -> usize {
+    len.min(MAX_PREALLOCATION / std::mem::size_of::<T>().max(1))
 }
@@ -1,3 +1,5 @@
+use crate::Config;
+
 /// trait for an object that can accept some kind of `Item`
 pub trait Receiver: Sized {
     /// the type of item that can be accepted, e.g. [`u8`]
@@ -23,12 +25,25 @@ pub trait Receiver: Sized {
         Ok(())
     }
 
+    /// the [`Config`] that determines the encoding of items written to this receiver
+    ///
+    /// # Note
+    /// The canonical implementation returns [`Config::DEFAULT`], wrap a receiver in [`crate::Configured`] to use another configuration.
+    fn config(&self) -> Config {
+        Config::DEFAULT
+    }
+
     /// automatically serialize a given object
+    ///
+    /// objects implementing [`SerializeRef`] can be passed by reference (e.g. `receiver.auto(&obj)`) to avoid moving or cloning them
     fn auto(&mut self, obj: impl Serialize<Self::Item>) -> Result<(), Self::Error> {
         obj.serialize(self)
     }
 
     /// alias for [`Self::auto`] in case a type implements [`crate::Sender`] *and* [`Receiver`]
+    #[deprecated(
+        note = "use `Receiver::auto` instead, `std::io::Write` types are wrapped in `esde::io::IoReceiver` now"
+    )]
     fn auto_ser(&mut self, obj: impl Serialize<Self::Item>) -> Result<(), Self::Error> {
         self.auto(obj)
     }
@@ -38,4 +53,100 @@ pub trait Receiver: Sized {
 pub trait Serialize<Item> {
     /// serialize `Self` into the given receiver
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error>;
+
+    /// serialize all elements of a vector one after another (no length stored)
+    ///
+    /// # Note
+    /// The canonical implementation calls [`Self::serialize`] for each element.
+    /// This method should be overridden if a better implementation can be made, e.g. [`u8`] passes the whole buffer to [`Receiver::accept_buffer`].
+    fn serialize_vec<R: Receiver<Item = Item>>(
+        items: Vec<Self>,
+        receiver: &mut R,
+    ) -> Result<(), R::Error>
+    where
+        Self: Sized,
+    {
+        for item in items {
+            item.serialize(receiver)?;
+        }
+        Ok(())
+    }
+
+    /// serialize all elements of an array one after another (no length stored)
+    ///
+    /// # Note
+    /// The canonical implementation calls [`Self::serialize`] for each element.
+    /// This method should be overridden if a better implementation can be made, e.g. [`u8`] passes the whole buffer to [`Receiver::accept_buffer`].
+    fn serialize_array<const N: usize, R: Receiver<Item = Item>>(
+        items: [Self; N],
+        receiver: &mut R,
+    ) -> Result<(), R::Error>
+    where
+        Self: Sized,
+    {
+        for item in items {
+            item.serialize(receiver)?;
+        }
+        Ok(())
+    }
+}
+
+/// trait for an object that can be serialized into `Item`s without being consumed
+///
+/// Any `&T` with `T: SerializeRef<Item>` implements [`Serialize<Item>`], so a reference can be passed wherever a [`Serialize`] is expected.
+/// The encoding must be the same as the one of the corresponding [`Serialize`] implementation (if there is one).
+/// Deriving `Serialize` implements [`SerializeRef`] as well.
+/// A type with a hand-written [`Serialize`] implementation that is [`Clone`] can implement `serialize_ref` as `self.clone().serialize(receiver)`, which makes e.g. slices and [`std::rc::Rc`]s of it serializable.
+///
+/// ```rust
+/// # use esde::*;
+/// struct Point(u32, u32);
+///
+/// impl<Item> SerializeRef<Item> for Point
+/// where
+///     u32: Serialize<Item>,
+/// {
+///     fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+///         receiver.auto(self.0)?;
+///         receiver.auto(self.1)
+///     }
+/// }
+///
+/// let points = vec![Point(1, 2), Point(3, 4)];
+/// let mut receiver = VecReceiver::new();
+/// receiver.auto(&points).unwrap();
+/// // `points` is still usable here
+/// assert_eq!(points.len(), 2);
+/// ```
+pub trait SerializeRef<Item> {
+    /// serialize `&self` into the given receiver
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error>;
+
+    /// serialize all elements of a slice one after another (no length stored)
+    ///
+    /// # Note
+    /// The canonical implementation calls [`Self::serialize_ref`] for each element.
+    /// This method should be overridden if a better implementation can be made, e.g. [`u8`] passes the whole slice to [`Receiver::accept_buffer`].
+    fn serialize_slice<R: Receiver<Item = Item>>(
+        items: &[Self],
+        receiver: &mut R,
+    ) -> Result<(), R::Error>
+    where
+        Self: Sized,
+    {
+        for item in items {
+            item.serialize_ref(receiver)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Item, T> Serialize<Item> for &T
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// references are saved just like the object they point to
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        self.serialize_ref(receiver)
+    }
 }
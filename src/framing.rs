@@ -0,0 +1,232 @@
+use crate::*;
+
+/// the default of [`FramedReader::with_max_frame_length`], 16 MiB
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 16 * 1024 * 1024;
+
+/// a [`Receiver`] wrapper that writes every value as a frame: the length of the encoded value as [`usize`] followed by the encoded value
+///
+/// With [`Self::with_checksum`], the length and the encoded value are each followed by their CRC-32 checksum as [`u32`].
+/// Frames are read by a [`FramedReader`], which knows where a value ends before decoding it and can therefore skip values it fails to decode.
+/// The encoding of the value and of the frame uses the [`Config`] of the receiver.
+///
+/// ```rust
+/// # use esde::*;
+/// let mut writer = FramedWriter::new(VecReceiver::new()).with_checksum();
+/// writer.send("hello").unwrap();
+/// writer.send(1337u16).unwrap();
+/// writer.send(42u16).unwrap();
+/// let buffer = writer.into_inner().into_inner();
+///
+/// let mut reader = FramedReader::new(SliceSender::new(&buffer)).with_checksum();
+/// let string: String = reader.receive().unwrap();
+/// assert_eq!(string, "hello");
+/// // reading the wrong type fails, but the whole frame is skipped nevertheless
+/// assert!(matches!(reader.receive::<u8>(), Err(Error::Parse(_))));
+/// let number: u16 = reader.receive().unwrap();
+/// assert_eq!(number, 42);
+/// assert!(reader.receive::<u16>().unwrap_err().is_eof());
+/// ```
+#[derive(Debug)]
+pub struct FramedWriter<R> {
+    receiver: R,
+    checksum: bool,
+}
+
+impl<R: Receiver<Item = u8>> FramedWriter<R> {
+    /// write frames (without checksum) to `receiver`
+    pub fn new(receiver: R) -> Self {
+        Self {
+            receiver,
+            checksum: false,
+        }
+    }
+
+    /// add CRC-32 checksums to every frame (the reader must expect them, see [`FramedReader::with_checksum`])
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = true;
+        self
+    }
+
+    /// get a reference to the wrapped receiver
+    pub fn get_ref(&self) -> &R {
+        &self.receiver
+    }
+
+    /// get a mutable reference to the wrapped receiver, e.g. to flush it
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.receiver
+    }
+
+    /// get back the wrapped receiver
+    pub fn into_inner(self) -> R {
+        self.receiver
+    }
+
+    /// encode `value` and write it as a frame
+    pub fn send(&mut self, value: impl Serialize<u8>) -> Result<(), R::Error> {
+        let config = self.receiver.config();
+        let payload = encode(value, config);
+        let header = encode(payload.len(), config);
+
+        self.receiver.accept_buffer(&header)?;
+        if self.checksum {
+            self.receiver.auto(crc32(&header))?;
+        }
+        self.receiver.accept_buffer(&payload)?;
+        if self.checksum {
+            self.receiver.auto(crc32(&payload))?;
+        }
+        Ok(())
+    }
+}
+
+/// a [`Sender`] wrapper that reads values written by a [`FramedWriter`]
+///
+/// A frame is always read completely before its value is decoded, so after the value fails to decode (e.g. a value of an unexpected type or a checksum mismatch of the value) the next frame can be read just fine.
+/// The value is decoded with the [`Config`] of the wrapped sender and the [`Limits`] set by [`Self::with_limits`], the offsets of parse errors within the value are relative to the start of the value.
+///
+/// The limits of the wrapped sender (e.g. a [`Limited`]) apply to the whole stream instead: its [`Limits::max_items`] counts the frame headers, the checksums and all previous frames as well.
+///
+/// A frame whose length cannot be trusted cannot be skipped, as its end is unknown.
+/// This is the case if the checksum of the length does not match ([`ParseErrorKind::HeaderChecksumMismatch`], only detected with [`Self::with_checksum`]) or if the frame is longer than the maximum (see [`Self::with_max_frame_length`]).
+/// These errors, just like errors while reading the frame itself (e.g. [`Error::EOF`] in the middle of a frame), leave the stream at an unknown position.
+#[derive(Debug)]
+pub struct FramedReader<S> {
+    sender: S,
+    checksum: bool,
+    max_frame_length: usize,
+    limits: Limits,
+}
+
+impl<S: Sender<Item = u8>> FramedReader<S> {
+    /// read frames (without checksum) of at most [`DEFAULT_MAX_FRAME_LENGTH`] bytes from `sender`, decoding the values without [`Limits`]
+    pub fn new(sender: S) -> Self {
+        Self {
+            sender,
+            checksum: false,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            limits: Limits::NONE,
+        }
+    }
+
+    /// expect CRC-32 checksums in every frame (see [`FramedWriter::with_checksum`])
+    ///
+    /// a mismatch is a [`ParseErrorKind::HeaderChecksumMismatch`] error for the length (the frame is not skipped in that case) and a [`ParseErrorKind::ChecksumMismatch`] error for the value (the frame is skipped)
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = true;
+        self
+    }
+
+    /// refuse frames longer than `max` bytes (instead of [`DEFAULT_MAX_FRAME_LENGTH`]) with a [`ParseErrorKind::LimitExceeded`] error
+    ///
+    /// the frame is not skipped in that case, reading on is not possible
+    pub fn with_max_frame_length(mut self, max: usize) -> Self {
+        self.max_frame_length = max;
+        self
+    }
+
+    /// apply `limits` to every value on its own (e.g. [`Limits::max_items`] is the maximum number of bytes of a single value)
+    ///
+    /// a frame whose value exceeds the limits is skipped
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// get a reference to the wrapped sender
+    pub fn get_ref(&self) -> &S {
+        &self.sender
+    }
+
+    /// get back the wrapped sender
+    pub fn into_inner(self) -> S {
+        self.sender
+    }
+
+    /// read the next frame and decode its value
+    ///
+    /// the value must use up the whole frame, otherwise a [`ParseErrorKind::TrailingItems`] or [`ParseErrorKind::IncompleteFrame`] error is returned
+    pub fn receive<T: Deserialize<u8>>(&mut self) -> Result<T, Error<S::Error>> {
+        let position = self.sender.position();
+        let config = self.sender.config();
+        let len: usize = self.sender.auto()?;
+        if self.checksum {
+            let expected: u32 = self.sender.auto()?;
+            let actual = crc32(&encode(len, config));
+            if expected != actual {
+                let kind = ParseErrorKind::HeaderChecksumMismatch { expected, actual };
+                return Err(Error::from(kind).at(position));
+            }
+        }
+        if len > self.max_frame_length {
+            let kind = ParseErrorKind::LimitExceeded {
+                limit: "max_frame_length",
+                max: self.max_frame_length,
+                value: len,
+            };
+            return Err(Error::from(kind).at(position));
+        }
+        let payload = u8::deserialize_vec(&mut self.sender, len)?;
+        if self.checksum {
+            let expected: u32 = self.sender.auto()?;
+            let actual = crc32(&payload);
+            if expected != actual {
+                let kind = ParseErrorKind::ChecksumMismatch { expected, actual };
+                return Err(Error::from(kind).at(position));
+            }
+        }
+
+        let sender = Configured::new(SliceSender::new(&payload), config);
+        let mut sender = Limited::new(sender, self.limits);
+        let value = sender.auto().and_then(|value| {
+            sender.into_inner().into_inner().finish()?;
+            Ok(value)
+        });
+        match value {
+            Ok(value) => Ok(value),
+            Err(Error::EOF) => Err(ParseErrorKind::IncompleteFrame.into()),
+            Err(Error::Parse(err)) => Err(Error::Parse(err)),
+            Err(Error::Sender(never)) => match never {},
+        }
+    }
+}
+
+/// encode `value` using `config`
+fn encode(value: impl Serialize<u8>, config: Config) -> Vec<u8> {
+    let mut receiver = Configured::new(VecReceiver::new(), config);
+    match receiver.auto(value) {
+        Ok(()) => {}
+        Err(never) => match never {},
+    }
+    receiver.into_inner().into_inner()
+}
+
+/// lookup table for [`crc32`], the remainder of each byte value
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+};
+
+/// the CRC-32 checksum (as used by e.g. zlib and PNG) of `bytes`
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0;
+    for &byte in bytes {
+        crc = (crc >> 8) ^ CRC32_TABLE[usize::from((crc as u8) ^ byte)];
+    }
+    !crc
+}
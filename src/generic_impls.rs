@@ -1,58 +1,17 @@
-use std::io::{Read, Write};
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    num::Wrapping,
+    ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+    rc::Rc,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+};
 
-use crate::*;
-
-// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
-// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
-// Sender and Receiver
-// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
-// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
-
-impl From<std::io::Error> for Error<std::io::Error> {
-    fn from(value: std::io::Error) -> Self {
-        Self::Sender(value)
-    }
-}
-
-/// any [`std::io::Read`] can send [`u8`]s
-impl<T: Read> Sender for T {
-    type Item = u8;
-    type Error = std::io::Error;
-
-    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
-        let mut buffer = [0; 1];
-        match self.read_exact(&mut buffer) {
-            Ok(_) => Ok(buffer[0]),
-            Err(err) => match err.kind() {
-                std::io::ErrorKind::UnexpectedEof => Err(Error::EOF),
-                _ => Err(err.into()),
-            },
-        }
-    }
-
-    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
-        match self.read_exact(buffer) {
-            Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Err(Error::EOF),
-            x => Ok(x?),
-        }
-    }
-
-    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
-        let mut buffer = [0; N];
-        self.fill_buffer(&mut buffer)?;
-        Ok(buffer)
-    }
-}
-
-/// any [`std::io::Write`] can receive [`u8`]s
-impl<T: Write> Receiver for T {
-    type Item = u8;
-    type Error = std::io::Error;
-
-    fn accept(&mut self, item: Self::Item) -> Result<(), Self::Error> {
-        self.write_all(&[item])
-    }
-}
+use crate::{de::cautious_capacity, *};
 
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
@@ -66,30 +25,26 @@ where
     usize: Deserialize<Item>,
 {
     /// reads vector as length given [`usize`] and then the corresponding amount of `Item`s
+    ///
+    /// the length is checked against [`Limits::max_length`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Vec<T>, Error<S::Error>> {
         let len = sender.auto()?;
-        let mut buffer = Vec::with_capacity(len);
-        for _ in 0..len {
-            buffer.push(sender.auto()?);
-        }
-        Ok(buffer)
+        sender.limits().check_length(len)?;
+        T::deserialize_vec(sender, len)
     }
 }
 
-impl<Item, T> Serialize<Item> for &[T]
+impl<Item, T> SerializeRef<Item> for [T]
 where
-    T: Serialize<Item> + Clone,
+    T: SerializeRef<Item>,
     usize: Serialize<Item>,
 {
-    /// saves a slice/vector (if items are cloneable) as the length (number of items) as a [`usize`] and then (clones) of the `Self::Item`s
-    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+    /// saves a slice as the length (number of items) as a [`usize`] and then the items (by reference)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
         receiver.auto(self.len())?;
-        for item in self {
-            receiver.auto(item.clone())?;
-        }
-        Ok(())
+        T::serialize_slice(self, receiver)
     }
 }
 
@@ -101,10 +56,18 @@ where
     /// saves a vector (moved here) as length (number of items) as a [`usize`] and then the [`Self::Item`]s
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
         receiver.auto(self.len())?;
-        for item in self {
-            receiver.auto(item)?;
-        }
-        Ok(())
+        T::serialize_vec(self, receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Vec<T>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saves a vector just like the corresponding slice
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_slice().serialize_ref(receiver)
     }
 }
 
@@ -119,12 +82,7 @@ impl<const N: usize, Item, T: Deserialize<Item>> Deserialize<Item> for [T; N] {
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<[T; N], Error<S::Error>> {
-        let mut buffer = Vec::with_capacity(N);
-        for _ in 0..N {
-            buffer.push(sender.auto()?);
-        }
-        let Ok(buffer) = buffer.try_into() else { unreachable!() };
-        Ok(buffer)
+        T::deserialize_array(sender)
     }
 }
 
@@ -134,10 +92,17 @@ where
 {
     /// saves fixed-size array simply as `N` `T`s one after another (no length stored)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        for item in self {
-            receiver.auto(item)?;
-        }
-        Ok(())
+        T::serialize_array(self, receiver)
+    }
+}
+
+impl<const N: usize, Item, T> SerializeRef<Item> for [T; N]
+where
+    T: SerializeRef<Item>,
+{
+    /// saves fixed-size array simply as `N` `T`s (by reference) one after another (no length stored)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        T::serialize_slice(self, receiver)
     }
 }
 
@@ -152,13 +117,17 @@ where
     T: Deserialize<Item>,
     bool: Deserialize<Item>,
 {
-    /// an option is read just like it would've been derived
+    /// an option is read just like it would've been derived (i.e. a [`bool`] tag, anything but 0 or 1 is an error)
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
         let is_some: bool = sender.auto()?;
         if is_some {
-            Ok(Some(sender.auto()?))
+            Ok(Some(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Variant("Some")))?,
+            ))
         } else {
             Ok(None)
         }
@@ -179,37 +148,1283 @@ where
     }
 }
 
+impl<Item, T> SerializeRef<Item> for Option<T>
+where
+    T: SerializeRef<Item>,
+    bool: Serialize<Item>,
+{
+    /// an option is saved just like it would've been derived
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            Some(x) => receiver.auto((true, x)),
+            None => receiver.auto(false),
+        }
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// Result
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, T, E> Deserialize<Item> for Result<T, E>
+where
+    T: Deserialize<Item>,
+    E: Deserialize<Item>,
+    u8: Deserialize<Item>,
+{
+    /// a result is read just like it would've been derived (i.e. a [`u8`] tag, 0 for `Ok` and 1 for `Err`)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let discriminant: u8 = sender.auto()?;
+        match discriminant {
+            0 => Ok(Ok(sender
+                .auto()
+                .map_err(|err| err.within(PathSegment::Variant("Ok")))?)),
+            1 => Ok(Err(sender
+                .auto()
+                .map_err(|err| err.within(PathSegment::Variant("Err")))?)),
+            _ => Err(ParseErrorKind::InvalidDiscriminant {
+                ty: "Result",
+                discriminant,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<Item, T, E> Serialize<Item> for Result<T, E>
+where
+    T: Serialize<Item>,
+    E: Serialize<Item>,
+    u8: Serialize<Item>,
+{
+    /// a result is saved just like it would've been derived
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            Ok(x) => receiver.auto((0u8, x)),
+            Err(x) => receiver.auto((1u8, x)),
+        }
+    }
+}
+
+impl<Item, T, E> SerializeRef<Item> for Result<T, E>
+where
+    T: SerializeRef<Item>,
+    E: SerializeRef<Item>,
+    u8: Serialize<Item>,
+{
+    /// a result is saved just like it would've been derived
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            Ok(x) => receiver.auto((0u8, x)),
+            Err(x) => receiver.auto((1u8, x)),
+        }
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// tuples
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+/// implements [`Deserialize`], [`Serialize`] and [`SerializeRef`] for tuples of the given element types and indices
+macro_rules! tuple_impls {
+    ($(($($name:ident $index:tt),*))*) => {
+        $(
+            impl<Item, $($name),*> Deserialize<Item> for ($($name,)*)
+            where
+                $($name: Deserialize<Item>,)*
+            {
+                /// tuples are read as their elements one after another (the unit type `()` reads nothing)
+                #[allow(unused_variables)]
+                fn deserialize<S: Sender<Item = Item> + ?Sized>(
+                    sender: &mut S,
+                ) -> Result<Self, Error<S::Error>> {
+                    Ok(($(
+                        sender
+                            .auto::<$name>()
+                            .map_err(|err| err.within(PathSegment::Field(stringify!($index))))?,
+                    )*))
+                }
+            }
+
+            impl<Item, $($name),*> Serialize<Item> for ($($name,)*)
+            where
+                $($name: Serialize<Item>,)*
+            {
+                /// tuples are saved as their elements one after another (the unit type `()` saves nothing)
+                #[allow(unused_variables)]
+                fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+                    $(receiver.auto(self.$index)?;)*
+                    Ok(())
+                }
+            }
+
+            impl<Item, $($name),*> SerializeRef<Item> for ($($name,)*)
+            where
+                $($name: SerializeRef<Item>,)*
+            {
+                /// tuples are saved as their elements (by reference) one after another (the unit type `()` saves nothing)
+                #[allow(unused_variables)]
+                fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+                    $(receiver.auto(&self.$index)?;)*
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+tuple_impls! {
+    ()
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15)
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// other collections
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+/// reads a length as [`usize`] (checked against [`Limits::max_length`]) and then that many `T`s, collecting them into `C`
+fn deserialize_collection<Item, T, C, S>(sender: &mut S) -> Result<C, Error<S::Error>>
+where
+    T: Deserialize<Item>,
+    C: FromIterator<T>,
+    usize: Deserialize<Item>,
+    S: Sender<Item = Item> + ?Sized,
+{
+    let len = sender.auto()?;
+    sender.limits().check_length(len)?;
+    (0..len)
+        .map(|index| {
+            sender
+                .auto()
+                .map_err(|err| err.within(PathSegment::Index(index)))
+        })
+        .collect()
+}
+
+/// reads a collection like [`deserialize_collection`], starting with `new(len)` and adding the elements with `insert`
+///
+/// `insert` returns whether the element was new, a duplicate is a [`ParseErrorKind::DuplicateKey`] error
+fn deserialize_unique<Item, T, C, S>(
+    sender: &mut S,
+    new: impl FnOnce(usize) -> C,
+    mut insert: impl FnMut(&mut C, T) -> bool,
+) -> Result<C, Error<S::Error>>
+where
+    T: Deserialize<Item>,
+    usize: Deserialize<Item>,
+    S: Sender<Item = Item> + ?Sized,
+{
+    let len = sender.auto()?;
+    sender.limits().check_length(len)?;
+    let mut collection = new(len);
+    for index in 0..len {
+        let position = sender.position();
+        let element = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Index(index)))?;
+        if !insert(&mut collection, element) {
+            return Err(Error::from(ParseErrorKind::DuplicateKey)
+                .at(position)
+                .within(PathSegment::Index(index)));
+        }
+    }
+    Ok(collection)
+}
+
+/// saves `len` as [`usize`] and then the `len` items one after another, just like a vector
+fn serialize_collection<Item, T, R>(
+    len: usize,
+    items: impl IntoIterator<Item = T>,
+    receiver: &mut R,
+) -> Result<(), R::Error>
+where
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
+    R: Receiver<Item = Item>,
+{
+    receiver.auto(len)?;
+    for item in items {
+        receiver.auto(item)?;
+    }
+    Ok(())
+}
+
+impl<Item, T> Deserialize<Item> for VecDeque<T>
+where
+    T: Deserialize<Item>,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let vector: Vec<T> = sender.auto()?;
+        Ok(vector.into())
+    }
+}
+
+impl<Item, T> Serialize<Item> for VecDeque<T>
+where
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (front to back)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(Vec::from(self))
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for VecDeque<T>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (front to back)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        let (front, back) = self.as_slices();
+        receiver.auto(self.len())?;
+        T::serialize_slice(front, receiver)?;
+        T::serialize_slice(back, receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for LinkedList<T>
+where
+    T: Deserialize<Item>,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        deserialize_collection(sender)
+    }
+}
+
+impl<Item, T> Serialize<Item> for LinkedList<T>
+where
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (front to back)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for LinkedList<T>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (front to back)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for BinaryHeap<T>
+where
+    T: Deserialize<Item> + Ord,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let vector: Vec<T> = sender.auto()?;
+        Ok(vector.into())
+    }
+}
+
+impl<Item, T> Serialize<Item> for BinaryHeap<T>
+where
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (in the internal order of the heap)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_vec())
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for BinaryHeap<T>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (in the internal order of the heap)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_slice().serialize_ref(receiver)
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// maps
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, K, V> Deserialize<Item> for BTreeMap<K, V>
+where
+    K: Deserialize<Item> + Ord,
+    V: Deserialize<Item>,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector of `(K, V)` tuples, a key that occurs twice is a [`ParseErrorKind::DuplicateKey`] error
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        deserialize_unique(
+            sender,
+            |_| BTreeMap::new(),
+            |map, (key, value)| map.insert(key, value).is_none(),
+        )
+    }
+}
+
+impl<Item, K, V> Serialize<Item> for BTreeMap<K, V>
+where
+    K: Serialize<Item>,
+    V: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector of `(K, V)` tuples (sorted by key)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, K, V> SerializeRef<Item> for BTreeMap<K, V>
+where
+    K: SerializeRef<Item>,
+    V: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector of `(K, V)` tuples (sorted by key)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, K, V, H> Deserialize<Item> for HashMap<K, V, H>
+where
+    K: Deserialize<Item> + Eq + Hash,
+    V: Deserialize<Item>,
+    H: BuildHasher + Default,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector of `(K, V)` tuples, a key that occurs twice is a [`ParseErrorKind::DuplicateKey`] error
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        deserialize_unique(
+            sender,
+            |len| {
+                let capacity = cautious_capacity::<(K, V)>(len);
+                HashMap::with_capacity_and_hasher(capacity, H::default())
+            },
+            |map, (key, value)| map.insert(key, value).is_none(),
+        )
+    }
+}
+
+impl<Item, K, V, H> Serialize<Item> for HashMap<K, V, H>
+where
+    K: Serialize<Item>,
+    V: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector of `(K, V)` tuples (in unspecified order, see [`Sorted`] for reproducible output)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, K, V, H> SerializeRef<Item> for HashMap<K, V, H>
+where
+    K: SerializeRef<Item>,
+    V: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector of `(K, V)` tuples (in unspecified order, see [`Sorted`] for reproducible output)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
-// Simple Tuple
+// sets
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 
-impl<Item, A, B> Deserialize<Item> for (A, B)
+impl<Item, T> Deserialize<Item> for BTreeSet<T>
 where
-    A: Deserialize<Item>,
-    B: Deserialize<Item>,
+    T: Deserialize<Item> + Ord,
+    usize: Deserialize<Item>,
 {
-    /// tuples are read as an `A` and `B` one after another
+    /// read just like a vector, an element that occurs twice is a [`ParseErrorKind::DuplicateKey`] error
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let a = sender.auto()?;
-        let b = sender.auto()?;
-        Ok((a, b))
+        deserialize_unique(sender, |_| BTreeSet::new(), BTreeSet::insert)
     }
 }
 
-impl<Item, A, B> Serialize<Item> for (A, B)
+impl<Item, T> Serialize<Item> for BTreeSet<T>
 where
-    A: Serialize<Item>,
-    B: Serialize<Item>,
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
 {
-    /// tuples are saved as an `A` and `B` one after another
+    /// saved just like a vector (sorted)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        let (a, b) = self;
-        receiver.auto(a)?;
-        receiver.auto(b)?;
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for BTreeSet<T>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (sorted)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, T, H> Deserialize<Item> for HashSet<T, H>
+where
+    T: Deserialize<Item> + Eq + Hash,
+    H: BuildHasher + Default,
+    usize: Deserialize<Item>,
+{
+    /// read just like a vector, an element that occurs twice is a [`ParseErrorKind::DuplicateKey`] error
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        deserialize_unique(
+            sender,
+            |len| {
+                let capacity = cautious_capacity::<T>(len);
+                HashSet::with_capacity_and_hasher(capacity, H::default())
+            },
+            HashSet::insert,
+        )
+    }
+}
+
+impl<Item, T, H> Serialize<Item> for HashSet<T, H>
+where
+    T: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (in unspecified order, see [`Sorted`] for reproducible output)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+impl<Item, T, H> SerializeRef<Item> for HashSet<T, H>
+where
+    T: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like a vector (in unspecified order, see [`Sorted`] for reproducible output)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        serialize_collection(self.len(), self, receiver)
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// ranges
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, T> Deserialize<Item> for Range<T>
+where
+    T: Deserialize<Item>,
+{
+    /// a range is read as its start, then its end
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let start = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("start")))?;
+        let end = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("end")))?;
+        Ok(start..end)
+    }
+}
+
+impl<Item, T> Serialize<Item> for Range<T>
+where
+    T: Serialize<Item>,
+{
+    /// a range is saved as its start, then its end
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((self.start, self.end))
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Range<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// a range is saved as its start, then its end
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((&self.start, &self.end))
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RangeInclusive<T>
+where
+    T: Deserialize<Item>,
+{
+    /// an inclusive range is read as its start, then its end
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let start = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("start")))?;
+        let end = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("end")))?;
+        Ok(start..=end)
+    }
+}
+
+impl<Item, T> Serialize<Item> for RangeInclusive<T>
+where
+    T: Serialize<Item>,
+{
+    /// an inclusive range is saved as its start, then its end (whether it was exhausted by iterating is not saved)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_inner())
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RangeInclusive<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// an inclusive range is saved as its start, then its end (whether it was exhausted by iterating is not saved)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((self.start(), self.end()))
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RangeFrom<T>
+where
+    T: Deserialize<Item>,
+{
+    /// a range without end is read as its start
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let start = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("start")))?;
+        Ok(start..)
+    }
+}
+
+impl<Item, T> Serialize<Item> for RangeFrom<T>
+where
+    T: Serialize<Item>,
+{
+    /// a range without end is saved as its start
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.start)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RangeFrom<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// a range without end is saved as its start
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.start.serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RangeTo<T>
+where
+    T: Deserialize<Item>,
+{
+    /// a range without start is read as its end
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let end = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("end")))?;
+        Ok(..end)
+    }
+}
+
+impl<Item, T> Serialize<Item> for RangeTo<T>
+where
+    T: Serialize<Item>,
+{
+    /// a range without start is saved as its end
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.end)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RangeTo<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// a range without start is saved as its end
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.end.serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RangeToInclusive<T>
+where
+    T: Deserialize<Item>,
+{
+    /// an inclusive range without start is read as its end
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let end = sender
+            .auto()
+            .map_err(|err| err.within(PathSegment::Field("end")))?;
+        Ok(..=end)
+    }
+}
+
+impl<Item, T> Serialize<Item> for RangeToInclusive<T>
+where
+    T: Serialize<Item>,
+{
+    /// an inclusive range without start is saved as its end
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.end)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RangeToInclusive<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// an inclusive range without start is saved as its end
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.end.serialize_ref(receiver)
+    }
+}
+
+impl<Item> Deserialize<Item> for RangeFull {
+    /// reads nothing
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        _sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(..)
+    }
+}
+
+impl<Item> Serialize<Item> for RangeFull {
+    /// saves nothing
+    fn serialize<R: Receiver<Item = Item>>(self, _receiver: &mut R) -> Result<(), R::Error> {
+        Ok(())
+    }
+}
+
+impl<Item> SerializeRef<Item> for RangeFull {
+    /// saves nothing
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, _receiver: &mut R) -> Result<(), R::Error> {
+        Ok(())
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Bound<T>
+where
+    T: Deserialize<Item>,
+    u8: Deserialize<Item>,
+{
+    /// a bound is read just like it would've been derived (i.e. a [`u8`] tag, 0 for `Included`, 1 for `Excluded` and 2 for `Unbounded`)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let discriminant: u8 = sender.auto()?;
+        match discriminant {
+            0 => {
+                Ok(Bound::Included(sender.auto().map_err(|err| {
+                    err.within(PathSegment::Variant("Included"))
+                })?))
+            }
+            1 => {
+                Ok(Bound::Excluded(sender.auto().map_err(|err| {
+                    err.within(PathSegment::Variant("Excluded"))
+                })?))
+            }
+            2 => Ok(Bound::Unbounded),
+            _ => Err(ParseErrorKind::InvalidDiscriminant {
+                ty: "Bound",
+                discriminant,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<Item, T> Serialize<Item> for Bound<T>
+where
+    T: Serialize<Item>,
+    u8: Serialize<Item>,
+{
+    /// a bound is saved just like it would've been derived
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            Bound::Included(x) => receiver.auto((0u8, x)),
+            Bound::Excluded(x) => receiver.auto((1u8, x)),
+            Bound::Unbounded => receiver.auto(2u8),
+        }
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Bound<T>
+where
+    T: SerializeRef<Item>,
+    u8: Serialize<Item>,
+{
+    /// a bound is saved just like it would've been derived
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            Bound::Included(x) => receiver.auto((0u8, x)),
+            Bound::Excluded(x) => receiver.auto((1u8, x)),
+            Bound::Unbounded => receiver.auto(2u8),
+        }
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// smart pointers
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, T> Deserialize<Item> for Box<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Box::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Box<[T]>
+where
+    Vec<T>: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let vector: Vec<T> = sender.auto()?;
+        Ok(vector.into_boxed_slice())
+    }
+}
+
+impl<Item> Deserialize<Item> for Box<str>
+where
+    String: Deserialize<Item>,
+{
+    /// read just like a [`String`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let string: String = sender.auto()?;
+        Ok(string.into_boxed_str())
+    }
+}
+
+impl<Item, T> Serialize<Item> for Box<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(*self)
+    }
+}
+
+impl<Item, T> Serialize<Item> for Box<[T]>
+where
+    Vec<T>: Serialize<Item>,
+{
+    /// saved just like a vector
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_vec())
+    }
+}
+
+impl<Item> Serialize<Item> for Box<str>
+where
+    String: Serialize<Item>,
+{
+    /// saved just like a [`String`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_string())
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Box<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        (**self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Rc<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Rc::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Rc<[T]>
+where
+    Vec<T>: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let vector: Vec<T> = sender.auto()?;
+        Ok(vector.into())
+    }
+}
+
+impl<Item> Deserialize<Item> for Rc<str>
+where
+    String: Deserialize<Item>,
+{
+    /// read just like a [`String`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let string: String = sender.auto()?;
+        Ok(string.into())
+    }
+}
+
+impl<Item, T> Serialize<Item> for Rc<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T` (by reference, as the value may be shared)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        (*self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Rc<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        (**self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Arc<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Arc::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Arc<[T]>
+where
+    Vec<T>: Deserialize<Item>,
+{
+    /// read just like a vector
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let vector: Vec<T> = sender.auto()?;
+        Ok(vector.into())
+    }
+}
+
+impl<Item> Deserialize<Item> for Arc<str>
+where
+    String: Deserialize<Item>,
+{
+    /// read just like a [`String`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let string: String = sender.auto()?;
+        Ok(string.into())
+    }
+}
+
+impl<Item, T> Serialize<Item> for Arc<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T` (by reference, as the value may be shared)
+    ///
+    /// derived types implement [`SerializeRef`], so they can be shared as well (the same goes for [`Rc`] and [`Cow`]):
+    ///
+    /// ```rust
+    /// # use esde::*;
+    /// # use std::sync::Arc;
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Settings {
+    ///     name: String,
+    ///     retries: u8,
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Job {
+    ///     id: u32,
+    ///     settings: Arc<Settings>,
+    /// }
+    ///
+    /// let settings = Arc::new(Settings {
+    ///     name: String::from("nightly"),
+    ///     retries: 3,
+    /// });
+    /// let jobs = vec![
+    ///     Job { id: 1, settings: Arc::clone(&settings) },
+    ///     Job { id: 2, settings },
+    /// ];
+    ///
+    /// let bytes = to_vec(&jobs);
+    /// let read: Vec<Job> = from_bytes(&bytes).unwrap();
+    /// assert_eq!(read, jobs);
+    /// ```
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        (*self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Arc<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        (**self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Deserialize<Item>,
+{
+    /// read just like the owned version of `T`, always resulting in [`Cow::Owned`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Cow::Owned(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for Cow<'_, T>
+where
+    T: ToOwned + SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        (*self).serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Cow<'_, T>
+where
+    T: ToOwned + SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        (**self).serialize_ref(receiver)
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// cells and locks
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, T> Deserialize<Item> for Cell<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Cell::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for Cell<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_inner())
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Cell<T>
+where
+    T: Serialize<Item> + Copy,
+{
+    /// saved just like `T` (a copy of the current value)
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.get())
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RefCell<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(RefCell::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for RefCell<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_inner())
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RefCell<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T`
+    ///
+    /// # panics
+    /// panics if the value is currently mutably borrowed, just like [`RefCell::borrow`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.borrow().serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Mutex<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Mutex::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for Mutex<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T` (even if the mutex is poisoned)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Mutex<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T` (even if the mutex is poisoned), blocks until the lock is acquired
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for RwLock<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(RwLock::new(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for RwLock<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T` (even if the lock is poisoned)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for RwLock<T>
+where
+    T: SerializeRef<Item> + ?Sized,
+{
+    /// saved just like `T` (even if the lock is poisoned), blocks until a read lock is acquired
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .serialize_ref(receiver)
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// other wrappers
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item, T> Deserialize<Item> for Wrapping<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Wrapping(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for Wrapping<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.0)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Wrapping<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.0.serialize_ref(receiver)
+    }
+}
+
+impl<Item, T> Deserialize<Item> for Reverse<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Reverse(sender.auto()?))
+    }
+}
+
+impl<Item, T> Serialize<Item> for Reverse<T>
+where
+    T: Serialize<Item>,
+{
+    /// saved just like `T`
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.0)
+    }
+}
+
+impl<Item, T> SerializeRef<Item> for Reverse<T>
+where
+    T: SerializeRef<Item>,
+{
+    /// saved just like `T`
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.0.serialize_ref(receiver)
+    }
+}
+
+impl<Item, T: ?Sized> Deserialize<Item> for PhantomData<T> {
+    /// reads nothing
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        _sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(PhantomData)
+    }
+}
+
+impl<Item, T: ?Sized> Serialize<Item> for PhantomData<T> {
+    /// saves nothing
+    fn serialize<R: Receiver<Item = Item>>(self, _receiver: &mut R) -> Result<(), R::Error> {
+        Ok(())
+    }
+}
+
+impl<Item, T: ?Sized> SerializeRef<Item> for PhantomData<T> {
+    /// saves nothing
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, _receiver: &mut R) -> Result<(), R::Error> {
         Ok(())
     }
 }
@@ -1,4 +1,18 @@
-use crate::*;
+use crate::{
+    de::MAX_PREALLOCATION,
+    varint::{deserialize_varint, serialize_varint, zigzag_decode, zigzag_encode},
+    *,
+};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::{
+    ffi::{CStr, CString, OsStr, OsString},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
@@ -8,7 +22,30 @@ use crate::*;
 
 impl Deserialize<u8> for u8 {
     fn deserialize<S: Sender<Item = u8> + ?Sized>(sender: &mut S) -> Result<Self, Error<S::Error>> {
-        Ok(sender.get()?)
+        sender.get()
+    }
+
+    /// checking `len` against [`Sender::check_remaining`], then reading chunks of at most 1 MiB via [`Sender::fill_buffer`]
+    fn deserialize_vec<S: Sender<Item = u8> + ?Sized>(
+        sender: &mut S,
+        len: usize,
+    ) -> Result<Vec<Self>, Error<S::Error>> {
+        sender.check_remaining(len)?;
+        let mut buffer = Vec::new();
+        while buffer.len() < len {
+            let start = buffer.len();
+            let chunk = (len - start).min(MAX_PREALLOCATION);
+            buffer.resize(start + chunk, 0);
+            sender.fill_buffer(&mut buffer[start..])?;
+        }
+        Ok(buffer)
+    }
+
+    /// reading via [`Sender::get_buffer`]
+    fn deserialize_array<const N: usize, S: Sender<Item = u8> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<[Self; N], Error<S::Error>> {
+        sender.get_buffer()
     }
 }
 
@@ -16,18 +53,51 @@ impl Serialize<u8> for u8 {
     fn serialize<R: Receiver<Item = u8>>(self, receiver: &mut R) -> Result<(), R::Error> {
         receiver.accept(self)
     }
+
+    /// saving via [`Receiver::accept_buffer`]
+    fn serialize_vec<R: Receiver<Item = u8>>(
+        items: Vec<Self>,
+        receiver: &mut R,
+    ) -> Result<(), R::Error> {
+        receiver.accept_buffer(&items)
+    }
+
+    /// saving via [`Receiver::accept_buffer`]
+    fn serialize_array<const N: usize, R: Receiver<Item = u8>>(
+        items: [Self; N],
+        receiver: &mut R,
+    ) -> Result<(), R::Error> {
+        receiver.accept_buffer(&items)
+    }
+}
+
+impl SerializeRef<u8> for u8 {
+    fn serialize_ref<R: Receiver<Item = u8>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.accept(*self)
+    }
+
+    /// saving via [`Receiver::accept_buffer`]
+    fn serialize_slice<R: Receiver<Item = u8>>(
+        items: &[Self],
+        receiver: &mut R,
+    ) -> Result<(), R::Error> {
+        receiver.accept_buffer(items)
+    }
 }
 
 impl<Item> Deserialize<Item> for u16
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => Ok(deserialize_varint(sender, Self::BITS)? as Self),
+        }
     }
 }
 
@@ -35,9 +105,13 @@ impl<Item> Serialize<Item> for u16
 where
     u8: Serialize<Item>,
 {
-    /// saving as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_be_bytes())
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(self.into(), receiver),
+        }
     }
 }
 
@@ -45,12 +119,15 @@ impl<Item> Deserialize<Item> for u32
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => Ok(deserialize_varint(sender, Self::BITS)? as Self),
+        }
     }
 }
 
@@ -58,9 +135,13 @@ impl<Item> Serialize<Item> for u32
 where
     u8: Serialize<Item>,
 {
-    /// saving as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_be_bytes())
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(self.into(), receiver),
+        }
     }
 }
 
@@ -68,12 +149,15 @@ impl<Item> Deserialize<Item> for u64
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => Ok(deserialize_varint(sender, Self::BITS)? as Self),
+        }
     }
 }
 
@@ -81,9 +165,13 @@ impl<Item> Serialize<Item> for u64
 where
     u8: Serialize<Item>,
 {
-    /// saving as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_be_bytes())
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(self.into(), receiver),
+        }
     }
 }
 
@@ -91,12 +179,15 @@ impl<Item> Deserialize<Item> for u128
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => Ok(deserialize_varint(sender, Self::BITS)? as Self),
+        }
     }
 }
 
@@ -104,9 +195,13 @@ impl<Item> Serialize<Item> for u128
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_be_bytes())
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(self, receiver),
+        }
     }
 }
 
@@ -114,13 +209,12 @@ impl<Item> Deserialize<Item> for usize
 where
     u64: Deserialize<Item>,
 {
-    /// saving as [`u64`] (even if `usize::BITS < 64`)
+    /// reading as [`u64`] (even if `usize::BITS < 64`), throwing error if the value does not fit into a [`usize`] on this platform
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        assert!(Self::BITS <= 64);
         let x: u64 = sender.auto()?;
-        Ok(x as Self)
+        Self::try_from(x).map_err(|_| ParseErrorKind::LengthOverflow(x).into())
     }
 }
 
@@ -128,9 +222,9 @@ impl<Item> Serialize<Item> for usize
 where
     u64: Serialize<Item>,
 {
-    /// reading as [`u64`] (even if `usize::BITS < 64`)
+    /// saving as [`u64`] (even if `usize::BITS < 64`)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        assert!(Self::BITS <= 64);
+        const { assert!(Self::BITS <= 64) };
         receiver.auto(self as u64)
     }
 }
@@ -158,7 +252,7 @@ impl<Item> Serialize<Item> for i8
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as big-endian array of [`u8`]s
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
         let buffer = self.to_be_bytes();
         receiver.auto(buffer)
@@ -169,12 +263,17 @@ impl<Item> Deserialize<Item> for i16
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => {
+                Ok(zigzag_decode(deserialize_varint(sender, Self::BITS)?) as Self)
+            }
+        }
     }
 }
 
@@ -182,10 +281,13 @@ impl<Item> Serialize<Item> for i16
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        let buffer = self.to_be_bytes();
-        receiver.auto(buffer)
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(zigzag_encode(self.into()), receiver),
+        }
     }
 }
 
@@ -193,12 +295,17 @@ impl<Item> Deserialize<Item> for i32
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => {
+                Ok(zigzag_decode(deserialize_varint(sender, Self::BITS)?) as Self)
+            }
+        }
     }
 }
 
@@ -206,10 +313,13 @@ impl<Item> Serialize<Item> for i32
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        let buffer = self.to_be_bytes();
-        receiver.auto(buffer)
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(zigzag_encode(self.into()), receiver),
+        }
     }
 }
 
@@ -217,12 +327,17 @@ impl<Item> Deserialize<Item> for i64
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => {
+                Ok(zigzag_decode(deserialize_varint(sender, Self::BITS)?) as Self)
+            }
+        }
     }
 }
 
@@ -230,10 +345,13 @@ impl<Item> Serialize<Item> for i64
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        let buffer = self.to_be_bytes();
-        receiver.auto(buffer)
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(zigzag_encode(self.into()), receiver),
+        }
     }
 }
 
@@ -241,12 +359,17 @@ impl<Item> Deserialize<Item> for i128
 where
     u8: Deserialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// reading as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Ok(Self::from_be_bytes(buffer))
+        let config = sender.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => Ok(Self::from_be_bytes(config.endian.reorder(sender.auto()?))),
+            IntEncoding::Varint => {
+                Ok(zigzag_decode(deserialize_varint(sender, Self::BITS)?) as Self)
+            }
+        }
     }
 }
 
@@ -254,10 +377,13 @@ impl<Item> Serialize<Item> for i128
 where
     u8: Serialize<Item>,
 {
-    /// reading as big-endian array of [`u8`]s
+    /// saving as array of [`u8`]s (in [`Config::endian`] byte order) or as ZigZag varint, depending on [`Config::int_encoding`]
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        let buffer = self.to_be_bytes();
-        receiver.auto(buffer)
+        let config = receiver.config();
+        match config.int_encoding {
+            IntEncoding::Fixed => receiver.auto(config.endian.reorder(self.to_be_bytes())),
+            IntEncoding::Varint => serialize_varint(zigzag_encode(self), receiver),
+        }
     }
 }
 
@@ -265,13 +391,12 @@ impl<Item> Deserialize<Item> for isize
 where
     i64: Deserialize<Item>,
 {
-    /// saving as [`i64`] (even if `usize::BITS < 64`)
+    /// reading as [`i64`] (even if `isize::BITS < 64`), throwing error if the value does not fit into an [`isize`] on this platform
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        assert!(Self::BITS <= i64::BITS);
         let x: i64 = sender.auto()?;
-        Ok(x as Self)
+        Self::try_from(x).map_err(|_| ParseErrorKind::IntegerOverflow { bits: Self::BITS }.into())
     }
 }
 
@@ -279,9 +404,9 @@ impl<Item> Serialize<Item> for isize
 where
     i64: Serialize<Item>,
 {
-    /// reading as [`i64`] (even if `usize::BITS < 64`)
+    /// saving as [`i64`] (even if `isize::BITS < 64`)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        assert!(Self::BITS <= i64::BITS);
+        const { assert!(Self::BITS <= i64::BITS) };
         receiver.auto(self as i64)
     }
 }
@@ -294,45 +419,49 @@ where
 
 impl<Item> Deserialize<Item> for f32
 where
-    u32: Deserialize<Item>,
+    u8: Deserialize<Item>,
 {
-    /// reading as [`u32`]
+    /// reading as array of [`u8`]s in [`Config::endian`] byte order (the bits of the float, never as varint)
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        Ok(Self::from_bits(sender.auto()?))
+        let endian = sender.config().endian;
+        Ok(Self::from_be_bytes(endian.reorder(sender.auto()?)))
     }
 }
 
 impl<Item> Serialize<Item> for f32
 where
-    u32: Serialize<Item>,
+    u8: Serialize<Item>,
 {
-    /// saving as [`u32`]
+    /// saving as array of [`u8`]s in [`Config::endian`] byte order (the bits of the float, never as varint)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_bits())
+        let endian = receiver.config().endian;
+        receiver.auto(endian.reorder(self.to_be_bytes()))
     }
 }
 
 impl<Item> Deserialize<Item> for f64
 where
-    u64: Deserialize<Item>,
+    u8: Deserialize<Item>,
 {
-    /// reading as [`u64`]
+    /// reading as array of [`u8`]s in [`Config::endian`] byte order (the bits of the float, never as varint)
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        Ok(Self::from_bits(sender.auto()?))
+        let endian = sender.config().endian;
+        Ok(Self::from_be_bytes(endian.reorder(sender.auto()?)))
     }
 }
 
 impl<Item> Serialize<Item> for f64
 where
-    u64: Serialize<Item>,
+    u8: Serialize<Item>,
 {
-    /// saving as [`u64`]
+    /// saving as array of [`u8`]s in [`Config::endian`] byte order (the bits of the float, never as varint)
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
-        receiver.auto(self.to_bits())
+        let endian = receiver.config().endian;
+        receiver.auto(endian.reorder(self.to_be_bytes()))
     }
 }
 
@@ -351,9 +480,7 @@ where
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
         let x = sender.auto()?;
-        Self::from_u32(x).ok_or(
-            format!("cannot parse char from u32 0x{x:x?} (not valid unicode character)").into(),
-        )
+        Self::from_u32(x).ok_or(ParseErrorKind::InvalidChar(x).into())
     }
 }
 
@@ -377,12 +504,16 @@ impl<Item> Deserialize<Item> for bool
 where
     u8: Deserialize<Item>,
 {
-    /// reading as [`u8`] (non-zero is treated as true, zero as false)
+    /// reading as [`u8`] (one is true, zero is false, anything else is an error)
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
         let x: u8 = sender.auto()?;
-        Ok(x != 0)
+        match x {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ParseErrorKind::InvalidBool(x).into()),
+        }
     }
 }
 
@@ -397,31 +528,722 @@ where
     }
 }
 
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// non-zero integers
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+/// implements [`Deserialize`], [`Serialize`] and [`SerializeRef`] for non-zero integers via their primitive counterpart
+macro_rules! non_zero_impls {
+    ($($ty:ident($int:ty)),* $(,)?) => {
+        $(
+            impl<Item> Deserialize<Item> for $ty
+            where
+                $int: Deserialize<Item>,
+            {
+                /// reading as [`
+                #[doc = stringify!($int)]
+                /// `] (zero is an error)
+                fn deserialize<S: Sender<Item = Item> + ?Sized>(
+                    sender: &mut S,
+                ) -> Result<Self, Error<S::Error>> {
+                    let x: $int = sender.auto()?;
+                    $ty::new(x).ok_or_else(|| {
+                        ParseErrorKind::UnexpectedZero {
+                            ty: stringify!($ty),
+                        }
+                        .into()
+                    })
+                }
+            }
+
+            impl<Item> Serialize<Item> for $ty
+            where
+                $int: Serialize<Item>,
+            {
+                /// saving as [`
+                #[doc = stringify!($int)]
+                /// `]
+                fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+                    receiver.auto(self.get())
+                }
+            }
+
+            impl<Item> SerializeRef<Item> for $ty
+            where
+                $int: Serialize<Item>,
+            {
+                /// saving a copy, just like [`Serialize`] does
+                fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+                    receiver.auto(self.get())
+                }
+            }
+        )*
+    };
+}
+
+non_zero_impls!(
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64),
+    NonZeroU128(u128),
+    NonZeroUsize(usize),
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroI128(i128),
+    NonZeroIsize(isize),
+);
+
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // other basic types
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 // %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
 
+/// read the bytes of a string-like type, i.e. the length as [`usize`] (checked against [`Limits::max_string_length`]) followed by that many [`u8`]s
+fn deserialize_string_bytes<Item, S>(sender: &mut S) -> Result<Vec<u8>, Error<S::Error>>
+where
+    u8: Deserialize<Item>,
+    S: Sender<Item = Item> + ?Sized,
+{
+    let len = sender.auto()?;
+    sender.limits().check_string_length(len)?;
+    u8::deserialize_vec(sender, len)
+}
+
 impl<Item> Deserialize<Item> for String
 where
     u8: Deserialize<Item>,
 {
     /// reading as vector of [`u8`]s, ṕarsing with [`String::from_utf8`] (throws error if invalid UTF-8)
+    ///
+    /// the length is checked against [`Limits::max_string_length`]
     fn deserialize<S: Sender<Item = Item> + ?Sized>(
         sender: &mut S,
     ) -> Result<Self, Error<S::Error>> {
-        let buffer = sender.auto()?;
-        Self::from_utf8(buffer).map_err(Error::make_parse)
+        let buffer = deserialize_string_bytes(sender)?;
+        Self::from_utf8(buffer).map_err(|err| ParseErrorKind::InvalidUtf8(err.utf8_error()).into())
     }
 }
 
 impl<Item> Serialize<Item> for String
 where
-    u8: Serialize<Item>,
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
 {
     /// saving as UTF-8 bytes (slice of [`u8`]s) via the [`String::as_bytes`] method
     fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
         receiver.auto(self.as_bytes())
     }
 }
+
+impl<Item> SerializeRef<Item> for String
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as UTF-8 bytes (slice of [`u8`]s) via the [`String::as_bytes`] method
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.as_bytes())
+    }
+}
+
+impl<Item> SerializeRef<Item> for str
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as UTF-8 bytes (slice of [`u8`]s) via the [`str::as_bytes`] method, can be read as a [`String`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.as_bytes())
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// OS strings and paths
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item> Deserialize<Item> for OsString
+where
+    u8: Deserialize<Item>,
+{
+    /// reading as vector of [`u8`]s, just like a [`String`]
+    ///
+    /// on unix, these are the raw bytes (via [`std::os::unix::ffi::OsStringExt`]).
+    /// on windows, they have to be valid [WTF-8](https://simonsapin.github.io/wtf-8/) (throws [`ParseErrorKind::InvalidUtf8`] otherwise).
+    /// elsewhere, they have to be valid UTF-8 (throws [`ParseErrorKind::InvalidUtf8`] otherwise).
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let buffer = deserialize_string_bytes(sender)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStringExt;
+            Ok(OsString::from_vec(buffer))
+        }
+        #[cfg(not(unix))]
+        {
+            String::from_utf8(buffer)
+                .map(OsString::from)
+                .or_else(|err| {
+                    #[cfg(windows)]
+                    if let Some(units) = wtf8_decode(err.as_bytes()) {
+                        use std::os::windows::ffi::OsStringExt;
+                        return Ok(OsString::from_wide(&units));
+                    }
+                    Err(ParseErrorKind::InvalidUtf8(err.utf8_error()).into())
+                })
+        }
+    }
+}
+
+impl<Item> SerializeRef<Item> for OsStr
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as slice of [`u8`]s, can be read as an [`OsString`] (or a [`String`] if valid UTF-8)
+    ///
+    /// on unix, these are the raw bytes (via [`std::os::unix::ffi::OsStrExt`]).
+    /// on windows, the UTF-16 code units (via `std::os::windows::ffi::OsStrExt`) are saved as [WTF-8](https://simonsapin.github.io/wtf-8/), i.e. as UTF-8 that allows unpaired surrogates.
+    /// elsewhere, they are the bytes of [`OsStr::as_encoded_bytes`].
+    /// either way, valid unicode is saved as its UTF-8 bytes.
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        #[cfg(unix)]
+        let bytes = {
+            use std::os::unix::ffi::OsStrExt;
+            self.as_bytes()
+        };
+        #[cfg(windows)]
+        let bytes = &{
+            use std::os::windows::ffi::OsStrExt;
+            wtf8_encode(self.encode_wide())
+        };
+        #[cfg(not(any(unix, windows)))]
+        let bytes = self.as_encoded_bytes();
+        receiver.auto(bytes)
+    }
+}
+
+impl<Item> Serialize<Item> for OsString
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving just like an [`OsStr`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_os_str().serialize_ref(receiver)
+    }
+}
+
+impl<Item> SerializeRef<Item> for OsString
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving just like an [`OsStr`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_os_str().serialize_ref(receiver)
+    }
+}
+
+/// encode UTF-16 code units as WTF-8, i.e. valid surrogate pairs as UTF-8 and unpaired surrogates as if they were code points
+#[cfg(windows)]
+fn wtf8_encode(units: impl Iterator<Item = u16>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for decoded in char::decode_utf16(units) {
+        match decoded {
+            Ok(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            Err(err) => {
+                let surrogate = err.unpaired_surrogate();
+                bytes.extend([
+                    0xe0 | (surrogate >> 12) as u8,
+                    0x80 | (surrogate >> 6 & 0x3f) as u8,
+                    0x80 | (surrogate & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    bytes
+}
+
+/// decode WTF-8 into UTF-16 code units, `None` if `bytes` are not valid WTF-8 (e.g. a surrogate pair saved as two code points)
+#[cfg(windows)]
+fn wtf8_decode(bytes: &[u8]) -> Option<Vec<u16>> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut after_lead_surrogate = false;
+    let mut rest = bytes;
+    while let Some(&first) = rest.first() {
+        let (len, min, bits) = match first {
+            0x00..=0x7f => (1, 0, first),
+            0xc2..=0xdf => (2, 0x80, first & 0x1f),
+            0xe0..=0xef => (3, 0x800, first & 0x0f),
+            0xf0..=0xf4 => (4, 0x10000, first & 0x07),
+            _ => return None,
+        };
+        let mut code_point = u32::from(bits);
+        for &byte in rest.get(1..len)? {
+            if byte & 0xc0 != 0x80 {
+                return None;
+            }
+            code_point = code_point << 6 | u32::from(byte & 0x3f);
+        }
+        if code_point < min || code_point > 0x10ffff {
+            return None;
+        }
+        rest = &rest[len..];
+
+        let is_trail_surrogate = (0xdc00..=0xdfff).contains(&code_point);
+        if after_lead_surrogate && is_trail_surrogate {
+            return None;
+        }
+        after_lead_surrogate = (0xd800..=0xdbff).contains(&code_point);
+        match code_point.checked_sub(0x10000) {
+            Some(offset) => units.extend([
+                0xd800 | (offset >> 10) as u16,
+                0xdc00 | (offset & 0x3ff) as u16,
+            ]),
+            None => units.push(code_point as u16),
+        }
+    }
+    Some(units)
+}
+
+impl<Item> Deserialize<Item> for PathBuf
+where
+    u8: Deserialize<Item>,
+{
+    /// reading as [`OsString`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let string: OsString = sender.auto()?;
+        Ok(string.into())
+    }
+}
+
+impl<Item> SerializeRef<Item> for Path
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as [`OsStr`], can be read as a [`PathBuf`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_os_str().serialize_ref(receiver)
+    }
+}
+
+impl<Item> Serialize<Item> for PathBuf
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as [`OsStr`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_os_str().serialize_ref(receiver)
+    }
+}
+
+impl<Item> SerializeRef<Item> for PathBuf
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as [`OsStr`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_os_str().serialize_ref(receiver)
+    }
+}
+
+impl<Item> Deserialize<Item> for CString
+where
+    u8: Deserialize<Item>,
+{
+    /// reading as vector of [`u8`]s without the trailing nul byte (throws [`ParseErrorKind::InteriorNul`] if it contains a nul byte)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let buffer = deserialize_string_bytes(sender)?;
+        CString::new(buffer).map_err(|err| ParseErrorKind::InteriorNul(err.nul_position()).into())
+    }
+}
+
+impl<Item> SerializeRef<Item> for CStr
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as slice of [`u8`]s without the trailing nul byte via the [`CStr::to_bytes`] method, can be read as a [`CString`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.to_bytes())
+    }
+}
+
+impl<Item> Serialize<Item> for CString
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as [`CStr`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_c_str().serialize_ref(receiver)
+    }
+}
+
+impl<Item> SerializeRef<Item> for CString
+where
+    u8: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saving as [`CStr`]
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        self.as_c_str().serialize_ref(receiver)
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// time
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+/// nanoseconds per second, the exclusive upper bound of the nanoseconds stored with a point in time or duration
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// read nanoseconds as [`u32`], throwing an error if they're not below one second
+fn deserialize_nanos<Item, S>(sender: &mut S) -> Result<u32, Error<S::Error>>
+where
+    u32: Deserialize<Item>,
+    S: Sender<Item = Item> + ?Sized,
+{
+    let position = sender.position();
+    let nanos: u32 = sender.auto()?;
+    if nanos < NANOS_PER_SEC {
+        Ok(nanos)
+    } else {
+        Err(Error::from(ParseErrorKind::InvalidNanos(nanos)).at(position))
+    }
+}
+
+impl<Item> Deserialize<Item> for Duration
+where
+    u64: Deserialize<Item>,
+    u32: Deserialize<Item>,
+{
+    /// reading the seconds as [`u64`], then the nanoseconds as [`u32`] (1e9 or more is an error)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let secs: u64 = sender.auto()?;
+        let nanos = deserialize_nanos(sender)?;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl<Item> Serialize<Item> for Duration
+where
+    u64: Serialize<Item>,
+    u32: Serialize<Item>,
+{
+    /// saving the seconds as [`u64`], then the subsecond nanoseconds as [`u32`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((self.as_secs(), self.subsec_nanos()))
+    }
+}
+
+impl<Item> Deserialize<Item> for SystemTime
+where
+    i64: Deserialize<Item>,
+    u32: Deserialize<Item>,
+{
+    /// reading just like it is saved (invalid nanoseconds or points in time not representable on this platform are an error)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let secs: i64 = sender.auto()?;
+        let nanos = deserialize_nanos(sender)?;
+        let total = i128::from(secs) * i128::from(NANOS_PER_SEC) + i128::from(nanos);
+        let distance = total.unsigned_abs();
+        let distance = Duration::new(
+            (distance / u128::from(NANOS_PER_SEC)) as u64,
+            (distance % u128::from(NANOS_PER_SEC)) as u32,
+        );
+        let time = if total >= 0 {
+            UNIX_EPOCH.checked_add(distance)
+        } else {
+            UNIX_EPOCH.checked_sub(distance)
+        };
+        time.ok_or_else(|| ParseErrorKind::TimeOutOfRange.into())
+    }
+}
+
+impl<Item> Serialize<Item> for SystemTime
+where
+    i64: Serialize<Item>,
+    u32: Serialize<Item>,
+{
+    /// saving the (whole) seconds since [`UNIX_EPOCH`] as [`i64`] (negative before it), then the nanoseconds on top as [`u32`]
+    ///
+    /// e.g. half a second before the epoch is saved as -1 seconds and 500,000,000 nanoseconds.
+    ///
+    /// # panics
+    /// panics if the seconds do not fit into an [`i64`] (which no common platform supports anyway)
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        // nanoseconds since the epoch, which cannot overflow an `i128` for any `Duration`
+        let total = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        };
+        let secs = total.div_euclid(i128::from(NANOS_PER_SEC));
+        let nanos = total.rem_euclid(i128::from(NANOS_PER_SEC)) as u32;
+        let secs = i64::try_from(secs).expect("point in time too far from the UNIX epoch");
+        receiver.auto((secs, nanos))
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// network addresses
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+impl<Item> Deserialize<Item> for Ipv4Addr
+where
+    u8: Deserialize<Item>,
+{
+    /// reading as array of 4 [`u8`]s
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let octets: [u8; 4] = sender.auto()?;
+        Ok(octets.into())
+    }
+}
+
+impl<Item> Serialize<Item> for Ipv4Addr
+where
+    u8: Serialize<Item>,
+{
+    /// saving as array of 4 [`u8`]s via the [`Ipv4Addr::octets`] method
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.octets())
+    }
+}
+
+impl<Item> Deserialize<Item> for Ipv6Addr
+where
+    u8: Deserialize<Item>,
+{
+    /// reading as array of 16 [`u8`]s
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let octets: [u8; 16] = sender.auto()?;
+        Ok(octets.into())
+    }
+}
+
+impl<Item> Serialize<Item> for Ipv6Addr
+where
+    u8: Serialize<Item>,
+{
+    /// saving as array of 16 [`u8`]s via the [`Ipv6Addr::octets`] method
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(self.octets())
+    }
+}
+
+impl<Item> Deserialize<Item> for IpAddr
+where
+    u8: Deserialize<Item>,
+{
+    /// reading just like it would've been derived (i.e. a [`u8`] tag, 0 for `V4` and 1 for `V6`)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let discriminant: u8 = sender.auto()?;
+        match discriminant {
+            0 => Ok(IpAddr::V4(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Variant("V4")))?,
+            )),
+            1 => Ok(IpAddr::V6(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Variant("V6")))?,
+            )),
+            _ => Err(ParseErrorKind::InvalidDiscriminant {
+                ty: "IpAddr",
+                discriminant,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<Item> Serialize<Item> for IpAddr
+where
+    u8: Serialize<Item>,
+{
+    /// saving just like it would've been derived
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            IpAddr::V4(ip) => receiver.auto((0u8, ip)),
+            IpAddr::V6(ip) => receiver.auto((1u8, ip)),
+        }
+    }
+}
+
+impl<Item> Deserialize<Item> for SocketAddrV4
+where
+    u8: Deserialize<Item>,
+    u16: Deserialize<Item>,
+{
+    /// reading the [`Ipv4Addr`], then the port as [`u16`]
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let (ip, port) = sender.auto()?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+impl<Item> Serialize<Item> for SocketAddrV4
+where
+    u8: Serialize<Item>,
+    u16: Serialize<Item>,
+{
+    /// saving the [`Ipv4Addr`], then the port as [`u16`]
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((*self.ip(), self.port()))
+    }
+}
+
+impl<Item> Deserialize<Item> for SocketAddrV6
+where
+    u8: Deserialize<Item>,
+    u16: Deserialize<Item>,
+    u32: Deserialize<Item>,
+{
+    /// reading the [`Ipv6Addr`], the port as [`u16`], then flowinfo and scope id as [`u32`]s
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let (ip, port, flowinfo, scope_id) = sender.auto()?;
+        Ok(SocketAddrV6::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+impl<Item> Serialize<Item> for SocketAddrV6
+where
+    u8: Serialize<Item>,
+    u16: Serialize<Item>,
+    u32: Serialize<Item>,
+{
+    /// saving the [`Ipv6Addr`], the port as [`u16`], then flowinfo and scope id as [`u32`]s
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto((*self.ip(), self.port(), self.flowinfo(), self.scope_id()))
+    }
+}
+
+impl<Item> Deserialize<Item> for SocketAddr
+where
+    u8: Deserialize<Item>,
+    u16: Deserialize<Item>,
+    u32: Deserialize<Item>,
+{
+    /// reading just like it would've been derived (i.e. a [`u8`] tag, 0 for `V4` and 1 for `V6`)
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        let discriminant: u8 = sender.auto()?;
+        match discriminant {
+            0 => Ok(SocketAddr::V4(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Variant("V4")))?,
+            )),
+            1 => Ok(SocketAddr::V6(
+                sender
+                    .auto()
+                    .map_err(|err| err.within(PathSegment::Variant("V6")))?,
+            )),
+            _ => Err(ParseErrorKind::InvalidDiscriminant {
+                ty: "SocketAddr",
+                discriminant,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<Item> Serialize<Item> for SocketAddr
+where
+    u8: Serialize<Item>,
+    u16: Serialize<Item>,
+    u32: Serialize<Item>,
+{
+    /// saving just like it would've been derived
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        match self {
+            SocketAddr::V4(addr) => receiver.auto((0u8, addr)),
+            SocketAddr::V6(addr) => receiver.auto((1u8, addr)),
+        }
+    }
+}
+
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// by reference
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+// %%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%%
+
+/// implements [`SerializeRef`] for [`Copy`] types by serializing a copy
+macro_rules! serialize_ref_by_copy {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<Item> SerializeRef<Item> for $ty
+            where
+                $ty: Serialize<Item>,
+            {
+                /// saving a copy, just like [`Serialize`] does
+                fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+                    (*self).serialize(receiver)
+                }
+            }
+        )*
+    };
+}
+
+serialize_ref_by_copy!(
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    char,
+    bool,
+    Duration,
+    SystemTime,
+    Ipv4Addr,
+    Ipv6Addr,
+    IpAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+    SocketAddr,
+);
@@ -0,0 +1,631 @@
+//! integration with [`std::io`]: adapters to use any [`Read`] as a [`Sender`] and any [`Write`] as a [`Receiver`] of [`u8`]s
+//!
+//! [`reader`] and [`writer`] create buffered adapters ([`BufSender`] and [`BufReceiver`]), so reading from or writing to e.g. a [`std::fs::File`] directly is fast.
+//! [`IoSender`] and [`IoReceiver`] pass every request on to the wrapped reader or writer, which is preferable if it is buffered already or in memory.
+//!
+//! Asynchronous IO is supported via the submodules `futures` and `tokio` (behind the cargo features of the same names), which exchange values in the frames of [`FramedWriter`] and [`FramedReader`].
+//!
+//! ```rust
+//! # use esde::*;
+//! let mut receiver = io::writer(Vec::new());
+//! receiver.auto("hi").unwrap();
+//! let buffer = receiver.into_inner().unwrap();
+//!
+//! let mut sender = io::reader(buffer.as_slice());
+//! let string: String = sender.auto().unwrap();
+//! assert_eq!(string, "hi");
+//! assert_eq!(sender.bytes_read(), 10);
+//! ```
+
+use std::io::{Read, Write};
+
+use crate::*;
+
+#[cfg(feature = "futures")]
+pub mod futures;
+
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+impl From<std::io::Error> for Error<std::io::Error> {
+    fn from(value: std::io::Error) -> Self {
+        Self::Sender(value)
+    }
+}
+
+/// EOF errors become [`std::io::ErrorKind::UnexpectedEof`], parse errors [`std::io::ErrorKind::InvalidData`]
+impl From<Error<std::io::Error>> for std::io::Error {
+    fn from(value: Error<std::io::Error>) -> Self {
+        match value {
+            Error::EOF => Self::new(std::io::ErrorKind::UnexpectedEof, value.to_string()),
+            Error::Sender(err) => err,
+            Error::Parse(err) => Self::new(std::io::ErrorKind::InvalidData, err),
+        }
+    }
+}
+
+/// serialize `obj` into a frame (see [`FramedWriter`]) using `config`, see the async receivers
+#[cfg(any(feature = "futures", feature = "tokio"))]
+fn encode_frame(obj: impl Serialize<u8>, config: Config, checksum: bool) -> Vec<u8> {
+    let mut writer = FramedWriter::new(Configured::new(VecReceiver::new(), config));
+    if checksum {
+        writer = writer.with_checksum();
+    }
+    match writer.send(obj) {
+        Ok(()) => writer.into_inner().into_inner().into_inner(),
+        Err(never) => match never {},
+    }
+}
+
+/// try to deserialize a value from the frame (see [`FramedReader`]) at the start of `buffer`, see the async senders
+///
+/// The bytes of the frame are removed from `buffer`, even if the value cannot be decoded.
+/// Returns `None` (leaving `buffer` as it is) if `buffer` does not contain the whole frame (yet), which is detected from the length of the frame without decoding anything.
+#[cfg(any(feature = "futures", feature = "tokio"))]
+fn try_decode_frame<T: Deserialize<u8>>(
+    buffer: &mut Vec<u8>,
+    config: Config,
+    limits: Limits,
+    checksum: bool,
+    max_frame_length: usize,
+) -> Result<Option<T>, Error<std::io::Error>> {
+    let sender = Configured::new(SliceSender::new(buffer), config);
+    let mut reader = FramedReader::new(sender)
+        .with_max_frame_length(max_frame_length)
+        .with_limits(limits);
+    if checksum {
+        reader = reader.with_checksum();
+    }
+    let result = reader.receive();
+    let len = reader.into_inner().into_inner().offset();
+    match result {
+        Ok(value) => {
+            buffer.drain(..len);
+            Ok(Some(value))
+        }
+        Err(Error::EOF) => Ok(None),
+        Err(Error::Parse(err)) => {
+            buffer.drain(..len);
+            Err(Error::Parse(err))
+        }
+        Err(Error::Sender(never)) => match never {},
+    }
+}
+
+/// the body of the modules `futures` and `tokio`, which differ only in the traits (which must be in scope) and in the doc examples
+///
+/// `$module` is the name of the module, `$block_on` a doc test line that starts running an `async` block
+#[cfg(any(feature = "futures", feature = "tokio"))]
+macro_rules! async_io {
+    ($module:literal, $block_on:literal) => {
+        /// an asynchronous counterpart of a [`Sender`] of [`u8`]s, reading frames (see [`FramedReader`]) from an [`AsyncRead`]
+        ///
+        /// Bytes are read into a buffer until it contains a complete frame, then the value is decoded from it.
+        /// Whether a frame is complete is known from its length, so values of any size are read in linear time.
+        /// The frames are written by an [`AsyncReceiver`], or by a [`FramedWriter`] in synchronous code.
+        /// Frames longer than [`DEFAULT_MAX_FRAME_LENGTH`] are refused by default, see [`Self::with_max_frame_length`].
+        ///
+        /// ```rust
+        /// # use esde::*;
+        #[doc = concat!(" # use esde::io::", $module, "::*;")]
+        #[doc = concat!(" ", $block_on)]
+        /// let mut writer = FramedWriter::new(VecReceiver::new());
+        /// writer.send("hi").unwrap();
+        /// writer.send(42u16).unwrap();
+        /// let data = writer.into_inner().into_inner();
+        /// let mut sender = AsyncSender::new(data.as_slice());
+        ///
+        /// let string: String = sender.auto().await.unwrap();
+        /// let number: u16 = sender.auto().await.unwrap();
+        /// assert_eq!((string.as_str(), number), ("hi", 42));
+        /// assert!(sender.auto::<u8>().await.unwrap_err().is_eof());
+        /// # });
+        /// ```
+        #[derive(Debug)]
+        pub struct AsyncSender<R> {
+            reader: R,
+            buffer: Vec<u8>,
+            config: Config,
+            limits: Limits,
+            checksum: bool,
+            max_frame_length: usize,
+        }
+
+        impl<R: AsyncRead + Unpin> AsyncSender<R> {
+            /// read frames (without checksum) from `reader` with the default [`Config`] and no [`Limits`]
+            pub fn new(reader: R) -> Self {
+                Self {
+                    reader,
+                    buffer: Vec::new(),
+                    config: Config::DEFAULT,
+                    limits: Limits::NONE,
+                    checksum: false,
+                    max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+                }
+            }
+
+            /// use `config` for all frames read from now on
+            pub fn with_config(mut self, config: Config) -> Self {
+                self.config = config;
+                self
+            }
+
+            /// apply `limits` to every value read from now on, see [`FramedReader::with_limits`] (the positions of errors are relative to the start of the value)
+            pub fn with_limits(mut self, limits: Limits) -> Self {
+                self.limits = limits;
+                self
+            }
+
+            /// expect CRC-32 checksums in every frame, see [`FramedReader::with_checksum`]
+            pub fn with_checksum(mut self) -> Self {
+                self.checksum = true;
+                self
+            }
+
+            /// refuse frames longer than `max` bytes, see [`FramedReader::with_max_frame_length`]
+            ///
+            /// this bounds the number of bytes buffered for a single value
+            pub fn with_max_frame_length(mut self, max: usize) -> Self {
+                self.max_frame_length = max;
+                self
+            }
+
+            /// the bytes that were read from the reader, but do not belong to a value yet
+            pub fn buffer(&self) -> &[u8] {
+                &self.buffer
+            }
+
+            /// get back the wrapped reader, any bytes in [`Self::buffer`] are lost
+            pub fn into_inner(self) -> R {
+                self.reader
+            }
+
+            /// read the next frame and decode its value, just like [`FramedReader::receive`]
+            ///
+            /// [`Error::EOF`] is returned if the reader ends before the frame is complete.
+            /// The frame is skipped after a parse error of its value, but not after an error in its length (see [`FramedReader`]).
+            ///
+            /// This method is cancel-safe: if the future is dropped before it completes (e.g. in `select!` or because of a timeout), no bytes are lost and the next call continues where this one left off.
+            pub async fn auto<D: Deserialize<u8>>(&mut self) -> Result<D, Error<std::io::Error>> {
+                let mut scratch = [0; crate::io::DEFAULT_BUFFER_SIZE];
+                loop {
+                    if let Some(value) = crate::io::try_decode_frame(
+                        &mut self.buffer,
+                        self.config,
+                        self.limits,
+                        self.checksum,
+                        self.max_frame_length,
+                    )? {
+                        return Ok(value);
+                    }
+                    let n = loop {
+                        match self.reader.read(&mut scratch).await {
+                            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                            result => break result?,
+                        }
+                    };
+                    if n == 0 {
+                        return Err(Error::EOF);
+                    }
+                    self.buffer.extend_from_slice(&scratch[..n]);
+                }
+            }
+        }
+
+        /// an asynchronous counterpart of a [`Receiver`] of [`u8`]s, writing frames (see [`FramedWriter`]) to an [`AsyncWrite`]
+        ///
+        /// Every value is serialized into memory first and then written as a whole frame.
+        /// The frames are read by an [`AsyncSender`], or by a [`FramedReader`] in synchronous code.
+        ///
+        /// ```rust
+        /// # use esde::*;
+        #[doc = concat!(" # use esde::io::", $module, "::*;")]
+        #[doc = concat!(" ", $block_on)]
+        /// let mut receiver = AsyncReceiver::new(Vec::new());
+        /// receiver.auto("hi").await.unwrap();
+        /// receiver.flush().await.unwrap();
+        /// let data = receiver.into_inner();
+        /// assert_eq!(data, [0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i']);
+        ///
+        /// let mut reader = FramedReader::new(SliceSender::new(&data));
+        /// assert_eq!(reader.receive::<String>().unwrap(), "hi");
+        /// # });
+        /// ```
+        #[derive(Debug)]
+        pub struct AsyncReceiver<W> {
+            writer: W,
+            config: Config,
+            checksum: bool,
+        }
+
+        impl<W: AsyncWrite + Unpin> AsyncReceiver<W> {
+            /// write frames (without checksum) to `writer` with the default [`Config`]
+            pub fn new(writer: W) -> Self {
+                Self {
+                    writer,
+                    config: Config::DEFAULT,
+                    checksum: false,
+                }
+            }
+
+            /// use `config` for all frames written from now on
+            pub fn with_config(mut self, config: Config) -> Self {
+                self.config = config;
+                self
+            }
+
+            /// add CRC-32 checksums to every frame, see [`FramedWriter::with_checksum`]
+            pub fn with_checksum(mut self) -> Self {
+                self.checksum = true;
+                self
+            }
+
+            /// get back the wrapped writer
+            pub fn into_inner(self) -> W {
+                self.writer
+            }
+
+            /// write a value as a frame, just like [`FramedWriter::send`]
+            pub async fn auto(&mut self, obj: impl Serialize<u8>) -> std::io::Result<()> {
+                let bytes = crate::io::encode_frame(obj, self.config, self.checksum);
+                self.writer.write_all(&bytes).await
+            }
+
+            /// flush the wrapped writer
+            pub async fn flush(&mut self) -> std::io::Result<()> {
+                self.writer.flush().await
+            }
+        }
+    };
+}
+#[cfg(any(feature = "futures", feature = "tokio"))]
+use async_io;
+
+/// the size of the buffer of [`BufSender`] and [`BufReceiver`] if not specified otherwise
+pub const DEFAULT_BUFFER_SIZE: usize = 8 * 1024;
+
+/// use `reader` as a [`Sender`] of [`u8`]s, reading ahead into a buffer
+pub fn reader<R: Read>(reader: R) -> BufSender<R> {
+    BufSender::new(reader)
+}
+
+/// use `writer` as a [`Receiver`] of [`u8`]s, collecting the items in a buffer (don't forget to call [`BufReceiver::flush`])
+pub fn writer<W: Write>(writer: W) -> BufReceiver<W> {
+    BufReceiver::new(writer)
+}
+
+/// a [`Sender`] of [`u8`]s reading from a [`Read`], keeping track of the number of bytes read
+///
+/// Every item is requested from the reader directly, use a [`BufSender`] for readers that make system calls (e.g. a [`std::fs::File`]).
+#[derive(Debug)]
+pub struct IoSender<R> {
+    reader: R,
+    position: usize,
+}
+
+impl<R> IoSender<R> {
+    /// read from `reader`
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            position: 0,
+        }
+    }
+
+    /// the number of bytes read so far
+    pub fn bytes_read(&self) -> usize {
+        self.position
+    }
+
+    /// get a reference to the wrapped reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// get a mutable reference to the wrapped reader (reading from it directly is not accounted for in [`Self::bytes_read`])
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// get back the wrapped reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Sender for IoSender<R> {
+    type Item = u8;
+    type Error = std::io::Error;
+
+    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
+        let [item] = self.get_buffer()?;
+        Ok(item)
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
+        match self.reader.read_exact(buffer) {
+            Ok(()) => {
+                self.position += buffer.len();
+                Ok(())
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Err(Error::EOF),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
+        let mut buffer = [0; N];
+        self.fill_buffer(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn position(&self) -> Option<usize> {
+        Some(self.position)
+    }
+}
+
+/// a [`Receiver`] of [`u8`]s writing to a [`Write`]
+///
+/// Every item is passed to the writer directly, use a [`BufReceiver`] for writers that make system calls (e.g. a [`std::fs::File`]).
+#[derive(Debug)]
+pub struct IoReceiver<W> {
+    writer: W,
+}
+
+impl<W> IoReceiver<W> {
+    /// write to `writer`
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// get a reference to the wrapped writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// get a mutable reference to the wrapped writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// get back the wrapped writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Receiver for IoReceiver<W> {
+    type Item = u8;
+    type Error = std::io::Error;
+
+    fn accept(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.writer.write_all(&[item])
+    }
+
+    fn accept_buffer(&mut self, items: &[Self::Item]) -> Result<(), Self::Error> {
+        self.writer.write_all(items)
+    }
+}
+
+/// a [`Sender`] of [`u8`]s reading ahead from a [`Read`] into a buffer, keeping track of the number of bytes read
+///
+/// Since more bytes may be read from the reader than are requested from this sender, the reader should not be used on its own afterwards.
+#[derive(Debug)]
+pub struct BufSender<R> {
+    reader: R,
+    buffer: Box<[u8]>,
+    start: usize,
+    end: usize,
+    position: usize,
+}
+
+impl<R> BufSender<R> {
+    /// read from `reader` with a buffer of [`DEFAULT_BUFFER_SIZE`] bytes
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, reader)
+    }
+
+    /// read from `reader` with a buffer of `capacity` bytes (at least one)
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        Self {
+            reader,
+            buffer: vec![0; capacity.max(1)].into_boxed_slice(),
+            start: 0,
+            end: 0,
+            position: 0,
+        }
+    }
+
+    /// the number of bytes read so far (not counting the ones that are buffered, but not requested yet)
+    pub fn bytes_read(&self) -> usize {
+        self.position
+    }
+
+    /// the bytes that were read from the reader, but not requested yet
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[self.start..self.end]
+    }
+
+    /// get a reference to the wrapped reader
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// get back the wrapped reader, any bytes in [`Self::buffer`] are lost
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> BufSender<R> {
+    /// replace the (used up) buffer with the next bytes from the reader, throwing [`Error::EOF`] if there are none
+    fn refill(&mut self) -> Result<(), Error<std::io::Error>> {
+        loop {
+            match self.reader.read(&mut self.buffer) {
+                Ok(0) => return Err(Error::EOF),
+                Ok(n) => {
+                    self.start = 0;
+                    self.end = n;
+                    return Ok(());
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+impl<R: Read> Sender for BufSender<R> {
+    type Item = u8;
+    type Error = std::io::Error;
+
+    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
+        if self.start == self.end {
+            self.refill()?;
+        }
+        let item = self.buffer[self.start];
+        self.start += 1;
+        self.position += 1;
+        Ok(item)
+    }
+
+    /// copies from the internal buffer, large requests are read into `buffer` directly
+    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
+        let mut filled = 0;
+        loop {
+            let available = &self.buffer[self.start..self.end];
+            let n = available.len().min(buffer.len() - filled);
+            buffer[filled..filled + n].copy_from_slice(&available[..n]);
+            self.start += n;
+            self.position += n;
+            filled += n;
+
+            let missing = &mut buffer[filled..];
+            if missing.is_empty() {
+                return Ok(());
+            }
+            if missing.len() >= self.buffer.len() {
+                return match self.reader.read_exact(missing) {
+                    Ok(()) => {
+                        self.position += missing.len();
+                        Ok(())
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Err(Error::EOF),
+                    Err(err) => Err(err.into()),
+                };
+            }
+            self.refill()?;
+        }
+    }
+
+    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
+        let mut buffer = [0; N];
+        self.fill_buffer(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn position(&self) -> Option<usize> {
+        Some(self.position)
+    }
+}
+
+/// a [`Receiver`] of [`u8`]s collecting the items in a buffer before passing them on to a [`Write`]
+///
+/// The buffer is written when it is full, when calling [`Self::flush`] or [`Self::into_inner`] and when the receiver is dropped.
+/// Errors are ignored in the last case, so calling [`Self::flush`] explicitly is recommended.
+#[derive(Debug)]
+pub struct BufReceiver<W: Write> {
+    /// always `Some`, except in [`Self::into_inner`]
+    writer: Option<W>,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> BufReceiver<W> {
+    /// write to `writer` with a buffer of [`DEFAULT_BUFFER_SIZE`] bytes
+    pub fn new(writer: W) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_SIZE, writer)
+    }
+
+    /// write to `writer` with a buffer of `capacity` bytes
+    pub fn with_capacity(capacity: usize, writer: W) -> Self {
+        Self {
+            writer: Some(writer),
+            buffer: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// get a reference to the wrapped writer
+    pub fn get_ref(&self) -> &W {
+        self.writer
+            .as_ref()
+            .expect("writer is only taken when consumed")
+    }
+
+    /// write the buffer to the writer, then flush the writer
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.write_buffer()?;
+        self.writer_mut().flush()
+    }
+
+    /// flush (see [`Self::flush`]), then get back the wrapped writer
+    pub fn into_inner(mut self) -> std::io::Result<W> {
+        self.flush()?;
+        Ok(self
+            .writer
+            .take()
+            .expect("writer is only taken when consumed"))
+    }
+
+    fn writer_mut(&mut self) -> &mut W {
+        self.writer
+            .as_mut()
+            .expect("writer is only taken when consumed")
+    }
+
+    /// write (and clear) the buffer without flushing the writer
+    fn write_buffer(&mut self) -> std::io::Result<()> {
+        if !self.buffer.is_empty() {
+            let writer = self
+                .writer
+                .as_mut()
+                .expect("writer is only taken when consumed");
+            writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Receiver for BufReceiver<W> {
+    type Item = u8;
+    type Error = std::io::Error;
+
+    fn accept(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        if self.buffer.len() == self.buffer.capacity() {
+            self.write_buffer()?;
+        }
+        self.buffer.push(item);
+        Ok(())
+    }
+
+    /// copies into the internal buffer, items that do not fit into it are written directly
+    fn accept_buffer(&mut self, items: &[Self::Item]) -> Result<(), Self::Error> {
+        if items.len() > self.buffer.capacity() - self.buffer.len() {
+            self.write_buffer()?;
+        }
+        if items.len() >= self.buffer.capacity() {
+            self.writer_mut().write_all(items)
+        } else {
+            self.buffer.extend_from_slice(items);
+            Ok(())
+        }
+    }
+}
+
+impl<W: Write> Drop for BufReceiver<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            let _ = self.write_buffer();
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! asynchronous IO with the traits of [`futures`](https://docs.rs/futures) (cargo feature `futures`)
+//!
+//! Values are (de)serialized in memory and transferred as whole frames, so the same [`Serialize`] and [`Deserialize`] implementations as for synchronous IO are used.
+//! The frames are compatible with [`FramedWriter`] and [`FramedReader`].
+
+use futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::*;
+
+crate::io::async_io!("futures", "# futures_executor::block_on(async {");
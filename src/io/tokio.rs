@@ -0,0 +1,13 @@
+//! asynchronous IO with the traits of [`tokio`](https://docs.rs/tokio) (cargo feature `tokio`)
+//!
+//! Values are (de)serialized in memory and transferred as whole frames, so the same [`Serialize`] and [`Deserialize`] implementations as for synchronous IO are used.
+//! The frames are compatible with [`FramedWriter`] and [`FramedReader`].
+
+use ::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::*;
+
+crate::io::async_io!(
+    "tokio",
+    "# tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {"
+);
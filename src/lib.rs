@@ -5,10 +5,13 @@
 //!
 //! This crate is focused on storing data in binary form, but can be adapted to store it in other formats.
 //! It also provides derive macros (`Deserialize` and `Serialize`) to derive the corresponding traits for structs and enums.
+//! Deriving `Serialize` implements [`SerializeRef`] as well (if all fields implement it), so derived types can be serialized by reference.
+//! Errors while deserializing a derived type name the field (and variant) they occurred in, see [`ParseError::path`].
 //!
 //! **note on cross-platform:**
 //! There are no particular guarantees.
 //! Most importantly, lengths of slices/vectors are stored as [`usize`]s, the length of which can vary from platform to platform.
+//! They are always saved as [`u64`]s, reading a value that does not fit into a [`usize`] on the current platform is a parse error (instead of truncating it).
 //!
 //! ## `Item`s
 //! Items are the units in which data is serialized.
@@ -17,7 +20,8 @@
 //!
 //! ## deserialization
 //! You have an object that provides `Item`s, implementing the [`Sender`] trait.
-//! This trait is automatically implemented for any [`std::io::Read`], "sending" [`u8`]s.
+//! Any [`std::io::Read`] can be used as a (buffered) sender of [`u8`]s by wrapping it with [`io::reader`].
+//! For data in memory, [`SliceSender`] keeps track of the position and can check for trailing items.
 //!
 //! Objects that can be deserialized from a stream of given `Item`s implement the [`Deserialize<Item>`] trait.
 //! Implementations for some primitive types are provided, most others should be derivable with the corresponding and intuitively named derive macro.
@@ -26,17 +30,37 @@
 //! In other words: One must know the type before the type can be parsed.
 //! No information what type is stored is provided by the library.
 //!
+//! Parse errors ([`ParseError`]) carry the path to the value that failed to decode (e.g. `[3].1`) and, if the sender knows its [`Sender::position`], the offset of that value.
+//!
+//! When reading untrusted data, wrap the sender in a [`Limited`] to restrict the number of items read and the lengths of collections and strings.
+//! Exceeding a [`Limits`] results in a parse error instead of huge allocations.
+//!
+//! Data in memory can also be read without copying: types implementing [`BorrowDeserialize`] (e.g. `&str` and `&[u8]`) borrow from a [`BorrowSender`] like [`SliceSender`], see [`BorrowSender::auto_borrow`].
+//!
 //! ## serialization
 //! You have an object than accepts `Item`s, implementing the [`Receiver`] trait.
-//! This trait is automatically implemented for any [`std::io::Write`], receiving [`u8`]s;
+//! Any [`std::io::Write`] can be used as a (buffered) receiver of [`u8`]s by wrapping it with [`io::writer`];
+//! for data in memory, [`VecReceiver`] cannot fail.
+//! The shortcuts [`to_vec`] and [`from_bytes`] convert values to and from bytes in memory directly.
 //!
 //! Any type that implements the [`Serialize<Item>`] trait can be serialized with any [`Receiver<Type = Item>`].
 //! Some implementations of primitve types are provided.
 //!
+//! ## framing
+//! To send many values over a pipe or a network connection, [`FramedWriter`] and [`FramedReader`] put each value in a length-prefixed frame (optionally with checksums).
+//! This way, a value that cannot be decoded can be skipped.
+//!
+//! ## configuration
+//! Some aspects of the encoding, e.g. fixed-width or variable-length integers, are determined by the [`Config`] of a sender/receiver.
+//! By default, [`Config::DEFAULT`] is used, wrap a sender/receiver in a [`Configured`] to change that.
+//! Data must be read with the same configuration it was written with.
+//!
 //! ## example
 //!
 //! ```rust
-//! #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+//! # use esde::*;
+//! # use std::fs::File;
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
 //! struct Struct {
 //!     another: AnotherStruct,
 //!     an_enum: Enum,
@@ -45,10 +69,10 @@
 //!     array: [String; 4],
 //! }
 //!
-//! #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
 //! struct AnotherStruct(u32, u32);
 //!
-//! #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
 //! enum Enum {
 //!     A,
 //!     B(f64),
@@ -72,21 +96,21 @@
 //!     };
 //!
 //!     {
-//!         let mut file = File::create(FILE)?;
-//!         file.auto_ser(example.clone())?;
-//!         // NOTE Receiver::auto could be used here if File didn't implement Read + Write
+//!         let mut receiver = io::writer(File::create(FILE)?);
+//!         receiver.auto(&example)?;
+//!         receiver.flush()?;
 //!     }
 //!
 //!     {
-//!         let mut file = File::open(FILE)?;
-//!         let person2: Struct = file.auto_de().map_err(Error::unwrap_sender)?;
-//!         // NOTE Sender::auto could be used here if File didn't implement Read + Write
+//!         let mut sender = io::reader(File::open(FILE)?);
+//!         let person2: Struct = sender.auto()?;
 //!         println!("person1: {:#?}", example);
 //!         println!("person2: {:#?}", person2);
 //!         assert_eq!(example, person2);
 //!         println!("indeed, they're equal")
 //!     }
 //!
+//! #   std::fs::remove_file(FILE)?;
 //!     Ok(())
 //! }
 //! ```
@@ -101,7 +125,32 @@ pub use es::*;
 mod de;
 pub use de::*;
 
+mod limits;
+pub use limits::*;
+
+mod config;
+pub use config::*;
+
+mod varint;
+
+pub mod io;
+
+mod slice;
+pub use slice::*;
+
+mod vec;
+pub use vec::*;
+
+mod framing;
+pub use framing::*;
+
+mod borrow;
+pub use borrow::*;
+
+mod sorted;
+pub use sorted::*;
+
 mod generic_impls;
 mod ingeneric_impls;
 
-pub use esde_derive::*;
+pub use esde_macros::{Deserialize, Serialize};
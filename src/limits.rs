@@ -0,0 +1,158 @@
+use crate::*;
+
+/// limits that are respected when decoding data, e.g. from untrusted sources
+///
+/// A [`Sender`] reports its limits via [`Sender::limits`], the simplest way to apply limits to any sender is wrapping it in [`Limited`].
+/// Exceeding a limit results in an [`Error::Parse`] instead of (potentially) huge allocations.
+/// Collections of zero-sized elements (e.g. `Vec<()>`) need no memory and take up no items, but reading them takes time proportional to their length, which is only bounded by [`Self::max_length`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Limits {
+    /// maximum number of `Item`s that may be read in total (e.g. the number of bytes for a [`u8`] sender)
+    pub max_items: Option<usize>,
+    /// maximum number of elements of a length-prefixed collection, e.g. a [`Vec`]
+    pub max_length: Option<usize>,
+    /// maximum length of a [`String`] (in bytes)
+    pub max_string_length: Option<usize>,
+}
+
+impl Limits {
+    /// no limits at all
+    pub const NONE: Self = Self {
+        max_items: None,
+        max_length: None,
+        max_string_length: None,
+    };
+
+    /// check the length of a collection against [`Self::max_length`]
+    pub fn check_length<E: std::error::Error>(&self, len: usize) -> Result<(), Error<E>> {
+        match self.max_length {
+            Some(max) if len > max => Err(ParseErrorKind::LimitExceeded {
+                limit: "max_length",
+                max,
+                value: len,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// check the length of a string against [`Self::max_string_length`]
+    pub fn check_string_length<E: std::error::Error>(&self, len: usize) -> Result<(), Error<E>> {
+        match self.max_string_length {
+            Some(max) if len > max => Err(ParseErrorKind::LimitExceeded {
+                limit: "max_string_length",
+                max,
+                value: len,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// a [`Sender`] that applies [`Limits`] to another sender
+///
+/// ```rust
+/// # use esde::*;
+/// let data: &[u8] = &[0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+/// let limits = Limits {
+///     max_length: Some(1024),
+///     ..Limits::NONE
+/// };
+/// let mut sender = Limited::new(SliceSender::new(data), limits);
+/// let result: Result<Vec<u8>, _> = sender.auto();
+/// assert!(matches!(result, Err(Error::Parse(_))));
+/// ```
+#[derive(Debug)]
+pub struct Limited<S> {
+    sender: S,
+    limits: Limits,
+    consumed: usize,
+}
+
+impl<S> Limited<S> {
+    /// wrap `sender`, applying `limits` to everything read from it
+    pub fn new(sender: S, limits: Limits) -> Self {
+        Self {
+            sender,
+            limits,
+            consumed: 0,
+        }
+    }
+
+    /// the number of `Item`s read so far
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// get back the wrapped sender
+    pub fn into_inner(self) -> S {
+        self.sender
+    }
+
+    /// the number of `Item`s consumed after `n` more, throwing an error if [`Limits::max_items`] would be exceeded
+    fn check_items<E: std::error::Error>(&self, n: usize) -> Result<usize, Error<E>> {
+        let consumed = self.consumed.saturating_add(n);
+        match self.limits.max_items {
+            Some(max) if consumed > max => Err(ParseErrorKind::LimitExceeded {
+                limit: "max_items",
+                max,
+                value: consumed,
+            }
+            .into()),
+            _ => Ok(consumed),
+        }
+    }
+
+    /// account for `n` more `Item`s, throwing an error if [`Limits::max_items`] would be exceeded
+    fn consume<E: std::error::Error>(&mut self, n: usize) -> Result<(), Error<E>> {
+        self.consumed = self.check_items(n)?;
+        Ok(())
+    }
+}
+
+impl<S: Sender> Sender for Limited<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
+        self.consume(1)?;
+        self.sender.get()
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
+        self.consume(buffer.len())?;
+        self.sender.fill_buffer(buffer)
+    }
+
+    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
+        self.consume(N)?;
+        self.sender.get_buffer()
+    }
+
+    fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// the position of the wrapped sender if it knows it, the number of items read through this wrapper otherwise
+    fn position(&self) -> Option<usize> {
+        self.sender.position().or(Some(self.consumed))
+    }
+
+    /// throws an error if reading `len` more items would exceed [`Limits::max_items`], then asks the wrapped sender
+    fn check_remaining(&self, len: usize) -> Result<(), Error<Self::Error>> {
+        self.check_items::<S::Error>(len)?;
+        self.sender.check_remaining(len)
+    }
+
+    fn config(&self) -> Config {
+        self.sender.config()
+    }
+}
+
+impl<'de, S: BorrowSender<'de>> BorrowSender<'de> for Limited<S> {
+    fn borrow_bytes(&mut self, len: usize) -> Result<&'de [u8], Error<Self::Error>> {
+        self.consume(len)?;
+        self.sender.borrow_bytes(len)
+    }
+}
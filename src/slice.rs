@@ -0,0 +1,122 @@
+use std::convert::Infallible;
+
+use crate::*;
+
+/// a [`Sender`] reading from a slice in memory, keeping track of its position
+///
+/// Running out of items results in [`Error::EOF`], no other errors can occur while reading items.
+/// Use [`Self::finish`] to make sure a value used up the whole slice.
+///
+/// ```rust
+/// # use esde::*;
+/// let data = [0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i', 42];
+/// let mut sender = SliceSender::new(&data);
+///
+/// let string: String = sender.auto().unwrap();
+/// assert_eq!(string, "hi");
+/// assert_eq!(sender.offset(), 10);
+/// assert_eq!(sender.peek(), Some(&42));
+///
+/// let result = sender.finish();
+/// assert!(matches!(result, Err(Error::Parse(_))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SliceSender<'a, T> {
+    slice: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> SliceSender<'a, T> {
+    /// start reading at the beginning of `slice`
+    pub fn new(slice: &'a [T]) -> Self {
+        Self { slice, position: 0 }
+    }
+
+    /// the number of items read so far (the same as [`Sender::position`], but without [`Option`])
+    pub fn offset(&self) -> usize {
+        self.position
+    }
+
+    /// the items that have not been read yet
+    pub fn remaining(&self) -> &'a [T] {
+        &self.slice[self.position..]
+    }
+
+    /// the next item without reading it, `None` if all items have been read
+    pub fn peek(&self) -> Option<&'a T> {
+        self.remaining().first()
+    }
+
+    /// whether all items have been read
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// make sure all items have been read, throwing a [`ParseErrorKind::TrailingItems`] error otherwise
+    pub fn finish(self) -> Result<(), Error<Infallible>> {
+        match self.remaining().len() {
+            0 => Ok(()),
+            count => Err(Error::from(ParseErrorKind::TrailingItems(count)).at(Some(self.position))),
+        }
+    }
+
+    /// advance past the next `len` items, returning them (throws [`Error::EOF`] if there are not enough)
+    fn take(&mut self, len: usize) -> Result<&'a [T], Error<Infallible>> {
+        let items = self.remaining().get(..len).ok_or(Error::EOF)?;
+        self.position += len;
+        Ok(items)
+    }
+}
+
+impl<T: Clone> Sender for SliceSender<'_, T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn get(&mut self) -> Result<Self::Item, Error<Self::Error>> {
+        Ok(self.take(1)?[0].clone())
+    }
+
+    fn fill_buffer(&mut self, buffer: &mut [Self::Item]) -> Result<(), Error<Self::Error>> {
+        buffer.clone_from_slice(self.take(buffer.len())?);
+        Ok(())
+    }
+
+    fn get_buffer<const N: usize>(&mut self) -> Result<[Self::Item; N], Error<Self::Error>> {
+        let items = self.take(N)?;
+        Ok(std::array::from_fn(|index| items[index].clone()))
+    }
+
+    fn position(&self) -> Option<usize> {
+        Some(self.position)
+    }
+
+    fn check_remaining(&self, len: usize) -> Result<(), Error<Self::Error>> {
+        if len > self.remaining().len() {
+            return Err(Error::EOF);
+        }
+        Ok(())
+    }
+}
+
+impl<'de> BorrowSender<'de> for SliceSender<'de, u8> {
+    fn borrow_bytes(&mut self, len: usize) -> Result<&'de [u8], Error<Self::Error>> {
+        self.take(len)
+    }
+}
+
+/// deserialize a value from `bytes`, throwing a [`ParseErrorKind::TrailingItems`] error if it does not use up all of them
+///
+/// ```rust
+/// # use esde::*;
+/// let value: Vec<u16> = from_bytes(&[0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 2]).unwrap();
+/// assert_eq!(value, [1, 2]);
+///
+/// let result: Result<u16, _> = from_bytes(&[0, 1, 0]);
+/// assert!(matches!(result, Err(Error::Parse(_))));
+/// ```
+pub fn from_bytes<T: Deserialize<u8>>(bytes: &[u8]) -> Result<T, Error<Infallible>> {
+    let mut sender = SliceSender::new(bytes);
+    let value = sender.auto()?;
+    sender.finish()?;
+    Ok(value)
+}
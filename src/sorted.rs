@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::*;
+
+/// wrapper to save a [`HashMap`] or [`HashSet`] sorted (by key), making the output reproducible
+///
+/// The layout is the same as the one of the wrapped collection, so data can be read with or without the wrapper.
+/// It can wrap the collection itself or a reference to it.
+///
+/// ```rust
+/// # use esde::*;
+/// # use std::collections::HashMap;
+/// let map = HashMap::from([(3u8, 'c'), (1, 'a'), (2, 'b')]);
+/// let buffer = to_vec(Sorted(&map));
+///
+/// let entries: Vec<(u8, char)> = from_bytes(&buffer).unwrap();
+/// assert_eq!(entries, [(1, 'a'), (2, 'b'), (3, 'c')]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sorted<T>(pub T);
+
+impl<Item, T> Deserialize<Item> for Sorted<T>
+where
+    T: Deserialize<Item>,
+{
+    /// read just like `T`
+    fn deserialize<S: Sender<Item = Item> + ?Sized>(
+        sender: &mut S,
+    ) -> Result<Self, Error<S::Error>> {
+        Ok(Self(sender.auto()?))
+    }
+}
+
+impl<Item, K, V, H> Serialize<Item> for Sorted<HashMap<K, V, H>>
+where
+    K: Serialize<Item> + Ord,
+    V: Serialize<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like the map, but sorted by key
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        let mut entries: Vec<_> = self.0.into_iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        receiver.auto(entries)
+    }
+}
+
+impl<Item, K, V, H> Serialize<Item> for Sorted<&HashMap<K, V, H>>
+where
+    K: SerializeRef<Item> + Ord,
+    V: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like the map, but sorted by key
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        let mut entries: Vec<_> = self.0.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        receiver.auto(entries)
+    }
+}
+
+impl<Item, K, V, H> SerializeRef<Item> for Sorted<HashMap<K, V, H>>
+where
+    K: SerializeRef<Item> + Ord,
+    V: SerializeRef<Item>,
+    usize: Serialize<Item>,
+{
+    /// saved just like the map, but sorted by key
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(Sorted(&self.0))
+    }
+}
+
+impl<Item, T, H> Serialize<Item> for Sorted<HashSet<T, H>>
+where
+    T: Serialize<Item> + Ord,
+    usize: Serialize<Item>,
+{
+    /// saved just like the set, but sorted
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        let mut items: Vec<_> = self.0.into_iter().collect();
+        items.sort_unstable();
+        receiver.auto(items)
+    }
+}
+
+impl<Item, T, H> Serialize<Item> for Sorted<&HashSet<T, H>>
+where
+    T: SerializeRef<Item> + Ord,
+    usize: Serialize<Item>,
+{
+    /// saved just like the set, but sorted
+    fn serialize<R: Receiver<Item = Item>>(self, receiver: &mut R) -> Result<(), R::Error> {
+        let mut items: Vec<_> = self.0.iter().collect();
+        items.sort_unstable();
+        receiver.auto(items)
+    }
+}
+
+impl<Item, T, H> SerializeRef<Item> for Sorted<HashSet<T, H>>
+where
+    T: SerializeRef<Item> + Ord,
+    usize: Serialize<Item>,
+{
+    /// saved just like the set, but sorted
+    fn serialize_ref<R: Receiver<Item = Item>>(&self, receiver: &mut R) -> Result<(), R::Error> {
+        receiver.auto(Sorted(&self.0))
+    }
+}
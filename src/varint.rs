@@ -0,0 +1,58 @@
+//! helpers for [`crate::IntEncoding::Varint`]
+
+use crate::*;
+
+/// save `value` as LEB128, i.e. 7 bits per [`u8`] (least significant first), the highest bit marking that more follow
+pub(crate) fn serialize_varint<Item, R>(mut value: u128, receiver: &mut R) -> Result<(), R::Error>
+where
+    u8: Serialize<Item>,
+    R: Receiver<Item = Item>,
+{
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            return receiver.auto(byte);
+        }
+        receiver.auto(byte | 0x80)?;
+    }
+}
+
+/// read a LEB128 encoded value, throwing an error if it does not fit into `bits` bits
+pub(crate) fn deserialize_varint<Item, S>(
+    sender: &mut S,
+    bits: u32,
+) -> Result<u128, Error<S::Error>>
+where
+    u8: Deserialize<Item>,
+    S: Sender<Item = Item> + ?Sized,
+{
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte: u8 = sender.auto()?;
+        let payload = u128::from(byte & 0x7f);
+        let part = payload << shift;
+        if part >> shift != payload || (bits < u128::BITS && part >> bits != 0) {
+            return Err(ParseErrorKind::IntegerOverflow { bits }.into());
+        }
+        value |= part;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+        if shift >= bits {
+            return Err(ParseErrorKind::IntegerOverflow { bits }.into());
+        }
+    }
+}
+
+/// map signed to unsigned integers such that small magnitudes stay small (0, -1, 1, -2, ... become 0, 1, 2, 3, ...)
+pub(crate) fn zigzag_encode(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+/// inverse of [`zigzag_encode`]
+pub(crate) fn zigzag_decode(value: u128) -> i128 {
+    (value >> 1) as i128 ^ -((value & 1) as i128)
+}
@@ -0,0 +1,71 @@
+use std::convert::Infallible;
+
+use crate::*;
+
+/// a [`Receiver`] collecting items in a [`Vec`] in memory, which can never fail
+///
+/// ```rust
+/// # use esde::*;
+/// let mut receiver = VecReceiver::new();
+/// receiver.auto("hi").unwrap();
+/// assert_eq!(receiver.into_inner(), [0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i']);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VecReceiver<T> {
+    vec: Vec<T>,
+}
+
+impl<T> VecReceiver<T> {
+    /// start with an empty vector
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+
+    /// append to an existing vector
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self { vec }
+    }
+
+    /// the items received so far
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// get back the vector of received items
+    pub fn into_inner(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Receiver for VecReceiver<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn accept(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.vec.push(item);
+        Ok(())
+    }
+
+    fn accept_buffer(&mut self, items: &[Self::Item]) -> Result<(), Self::Error>
+    where
+        Self::Item: Clone,
+    {
+        self.vec.extend_from_slice(items);
+        Ok(())
+    }
+}
+
+/// serialize `value` into a new vector of bytes (pass a reference to serialize types implementing [`SerializeRef`] without consuming them)
+///
+/// ```rust
+/// # use esde::*;
+/// let bytes = to_vec(&vec![1u16, 2]);
+/// assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 0, 2, 0, 1, 0, 2]);
+/// ```
+pub fn to_vec(value: impl Serialize<u8>) -> Vec<u8> {
+    let mut receiver = VecReceiver::new();
+    match receiver.auto(value) {
+        Ok(()) => receiver.into_inner(),
+        Err(never) => match never {},
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "futures")]
+
+use std::{
+    future::Future,
+    io::Result,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+
+use common::*;
+use esde::{io::futures::*, *};
+use futures_executor::block_on;
+use futures_util::{io::AsyncRead, task::noop_waker};
+
+mod common;
+
+/// an [`AsyncRead`] that returns its chunks one by one, each after being pending once
+struct Chunks {
+    chunks: Vec<Vec<u8>>,
+    pending: bool,
+}
+
+impl Chunks {
+    fn new(chunks: Vec<Vec<u8>>) -> Self {
+        Self {
+            chunks,
+            pending: true,
+        }
+    }
+}
+
+impl AsyncRead for Chunks {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        if self.pending {
+            self.pending = false;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        self.pending = true;
+        if self.chunks.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let chunk = self.chunks.remove(0);
+        assert!(chunk.len() <= buf.len());
+        buf[..chunk.len()].copy_from_slice(&chunk);
+        Poll::Ready(Ok(chunk.len()))
+    }
+}
+
+#[test]
+fn round_trip_in_small_chunks() {
+    let bytes = frames(["one", "two", "three"], true);
+    let chunks = bytes.chunks(3).map(<[u8]>::to_vec).collect();
+    let mut sender = AsyncSender::new(Chunks::new(chunks)).with_checksum();
+    block_on(async {
+        for expected in ["one", "two", "three"] {
+            assert_eq!(sender.auto::<String>().await.unwrap(), expected);
+        }
+        assert!(sender.auto::<String>().await.unwrap_err().is_eof());
+    });
+}
+
+#[test]
+fn large_value() {
+    let value = vec![7u8; 4 * 1024 * 1024];
+    let bytes = frames([&value], true);
+    let mut sender = AsyncSender::new(bytes.as_slice()).with_checksum();
+    assert_eq!(block_on(sender.auto::<Vec<u8>>()).unwrap(), value);
+}
+
+#[test]
+fn cancelled_read_loses_nothing() {
+    let bytes = frames([7u32, 8], true);
+    let (first, second) = bytes.split_at(5);
+    let mut sender =
+        AsyncSender::new(Chunks::new(vec![first.to_vec(), second.to_vec()])).with_checksum();
+
+    // the first poll finds the reader pending, the second one reads the first chunk and waits for the second one
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    {
+        let mut future = pin!(sender.auto::<u32>());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+    assert_eq!(sender.buffer(), first);
+
+    block_on(async {
+        assert_eq!(sender.auto::<u32>().await.unwrap(), 7);
+        assert_eq!(sender.auto::<u32>().await.unwrap(), 8);
+    });
+}
+
+#[test]
+fn parse_errors_skip_the_frame() {
+    let bytes = frames([1u16, 2], true);
+    let mut sender = AsyncSender::new(bytes.as_slice()).with_checksum();
+    block_on(async {
+        let err = sender.auto::<u8>().await.unwrap_err();
+        assert!(matches!(parse_kind(&err), ParseErrorKind::TrailingItems(1)));
+        assert_eq!(sender.auto::<u16>().await.unwrap(), 2);
+    });
+}
+
+#[test]
+fn frame_length_above_max() {
+    let bytes = frames([[0u8; 16]], true);
+    let mut sender = AsyncSender::new(bytes.as_slice())
+        .with_checksum()
+        .with_max_frame_length(15);
+    let err = block_on(sender.auto::<[u8; 16]>()).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_frame_length",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn limits_apply_to_every_value_on_its_own() {
+    // each value takes up exactly 10 bytes, the frames take up 18 bytes each
+    let limits = Limits {
+        max_items: Some(10),
+        ..Limits::NONE
+    };
+    let bytes = frames([(1u64, 2u16); 3], true);
+    let mut sender = AsyncSender::new(bytes.as_slice())
+        .with_checksum()
+        .with_limits(limits);
+    block_on(async {
+        for _ in 0..3 {
+            assert_eq!(sender.auto::<(u64, u16)>().await.unwrap(), (1, 2));
+        }
+    });
+
+    let bytes = frames([(1u64, 2u32), (3, 4)], true);
+    let mut sender = AsyncSender::new(bytes.as_slice())
+        .with_checksum()
+        .with_limits(limits);
+    block_on(async {
+        let err = sender.auto::<(u64, u32)>().await.unwrap_err();
+        assert!(matches!(
+            parse_kind(&err),
+            ParseErrorKind::LimitExceeded {
+                limit: "max_items",
+                max: 10,
+                value: 12,
+            }
+        ));
+        // the frame was skipped, the next one exceeds the limit on its own as well
+        let err = sender.auto::<(u64, u32)>().await.unwrap_err();
+        assert!(err.as_parse().is_some());
+        assert!(sender.buffer().is_empty());
+    });
+}
+
+#[test]
+fn interoperates_with_framing() {
+    let mut writer = FramedWriter::new(VecReceiver::new()).with_checksum();
+    writer.send((1u8, "sync")).unwrap();
+    let bytes = writer.into_inner().into_inner();
+    let mut sender = AsyncSender::new(bytes.as_slice()).with_checksum();
+    let value: (u8, String) = block_on(sender.auto()).unwrap();
+    assert_eq!(value, (1, String::from("sync")));
+
+    let bytes = block_on(async {
+        let mut receiver = AsyncReceiver::new(Vec::new()).with_checksum();
+        receiver.auto((2u8, "async")).await.unwrap();
+        receiver.into_inner()
+    });
+    assert_eq!(bytes, frames([(2u8, "async")], true));
+    let mut reader = FramedReader::new(SliceSender::new(&bytes)).with_checksum();
+    let value: (u8, String) = reader.receive().unwrap();
+    assert_eq!(value, (2, String::from("async")));
+}
@@ -0,0 +1,79 @@
+use std::borrow::Cow;
+
+use esde::*;
+
+/// borrow a `T` from `bytes`, making sure all of them are used up
+fn borrow<'de, T: BorrowDeserialize<'de>>(bytes: &'de [u8]) -> T {
+    let mut sender = SliceSender::new(bytes);
+    let value = sender.auto_borrow().unwrap();
+    sender.finish().unwrap();
+    value
+}
+
+#[test]
+fn mixed_borrowed_and_owned() {
+    let bytes = to_vec(("name", 42u32));
+    assert_eq!(borrow::<(&str, u32)>(&bytes), ("name", 42));
+
+    let bytes = to_vec(Some(7u32));
+    assert_eq!(borrow::<Option<u32>>(&bytes), Some(7));
+
+    let bytes = to_vec(vec![("a", true), ("b", false)]);
+    assert_eq!(
+        borrow::<Vec<(&str, bool)>>(&bytes),
+        [("a", true), ("b", false)]
+    );
+
+    let bytes = to_vec(["x", "y"]);
+    assert_eq!(borrow::<[&str; 2]>(&bytes), ["x", "y"]);
+
+    let bytes = to_vec((String::from("owned"), &b"raw"[..], -1.5f64, 'c'));
+    let (owned, raw, float, c): (String, Cow<[u8]>, f64, char) = borrow(&bytes);
+    assert_eq!(
+        (owned.as_str(), &*raw, float, c),
+        ("owned", &b"raw"[..], -1.5, 'c')
+    );
+}
+
+#[test]
+fn borrowed_strings_point_into_the_input() {
+    let bytes = to_vec(("first", "second"));
+    let (first, second): (&str, &str) = borrow(&bytes);
+    let range = bytes.as_ptr_range();
+    assert!(range.contains(&first.as_ptr()));
+    assert!(range.contains(&second.as_ptr()));
+}
+
+#[test]
+fn errors_have_paths() {
+    let mut bytes = to_vec(vec![("a", 1u8), ("b", 2)]);
+    // make the second string invalid UTF-8
+    let index = bytes.len() - 2;
+    bytes[index] = 0xff;
+    let mut sender = SliceSender::new(&bytes);
+    let err = sender
+        .auto_borrow::<Vec<(&str, u8)>>()
+        .unwrap_err()
+        .into_parse()
+        .unwrap();
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidUtf8(_)));
+    assert_eq!(err.path(), [PathSegment::Index(1), PathSegment::Field("0")]);
+}
+
+#[test]
+fn limits_apply() {
+    let limits = Limits {
+        max_string_length: Some(3),
+        ..Limits::NONE
+    };
+    let bytes = to_vec(("long string", 1u8));
+    let mut sender = Limited::new(SliceSender::new(&bytes), limits);
+    let err = sender.auto_borrow::<(&str, u8)>().unwrap_err();
+    assert!(matches!(
+        err.as_parse().unwrap().kind(),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_string_length",
+            ..
+        }
+    ));
+}
@@ -0,0 +1,157 @@
+use std::io::{ErrorKind, Read, Write};
+
+use esde::{io::*, *};
+
+/// a [`Read`] that returns at most `max` bytes per call (interrupting every other call) and records the size of every request
+struct ShortReader<'a> {
+    data: &'a [u8],
+    max: usize,
+    interrupt: bool,
+    requests: Vec<usize>,
+}
+
+impl<'a> ShortReader<'a> {
+    fn new(data: &'a [u8], max: usize) -> Self {
+        Self {
+            data,
+            max,
+            interrupt: true,
+            requests: Vec::new(),
+        }
+    }
+}
+
+impl Read for ShortReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(ErrorKind::Interrupted.into());
+        }
+        self.requests.push(buf.len());
+        let n = buf.len().min(self.max).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+/// a [`Write`] that records every call
+#[derive(Default)]
+struct RecordingWriter {
+    writes: Vec<Vec<u8>>,
+}
+
+impl Write for RecordingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writes.push(buf.to_vec());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+type Value = (u8, u32, String, Vec<u8>, u64);
+
+fn value() -> Value {
+    (
+        1,
+        0x0203_0405,
+        String::from("a string of some length"),
+        (0..=255).cycle().take(1000).collect(),
+        u64::MAX,
+    )
+}
+
+#[test]
+fn reading_with_any_capacity() {
+    let bytes = to_vec((value(), value()));
+    for capacity in [0, 1, 2, 3, 8, 1024, DEFAULT_BUFFER_SIZE] {
+        for max in [1, 2, 5, 100, usize::MAX] {
+            let mut sender = BufSender::with_capacity(capacity, ShortReader::new(&bytes, max));
+            assert_eq!(sender.auto::<Value>().unwrap(), value(), "{capacity} {max}");
+            assert_eq!(sender.auto::<Value>().unwrap(), value(), "{capacity} {max}");
+            assert_eq!(sender.bytes_read(), bytes.len());
+            assert!(sender.auto::<u8>().unwrap_err().is_eof());
+        }
+    }
+}
+
+#[test]
+fn reading_large_requests_directly() {
+    let bytes = to_vec(vec![7u8; 100]);
+    let mut sender = BufSender::with_capacity(4, ShortReader::new(&bytes, usize::MAX));
+    assert_eq!(sender.auto::<Vec<u8>>().unwrap(), [7; 100]);
+    // both the length and the elements are larger than the buffer, so they are read without it
+    assert_eq!(sender.get_ref().requests, [8, 100]);
+    assert!(sender.buffer().is_empty());
+}
+
+#[test]
+fn reading_across_chunks() {
+    let bytes = to_vec((1u8, 0x0203u16, 0x0405_0607_0809_0a0bu64));
+    let mut sender = BufSender::with_capacity(4, ShortReader::new(&bytes, usize::MAX));
+    assert_eq!(sender.auto::<u8>().unwrap(), 1);
+    assert_eq!(sender.buffer(), [2, 3, 4]);
+    assert_eq!(sender.auto::<u16>().unwrap(), 0x0203);
+    // one byte from the buffer, then the rest (larger than the buffer) directly
+    assert_eq!(sender.auto::<u64>().unwrap(), 0x0405_0607_0809_0a0b);
+    assert_eq!(sender.get_ref().requests, [4, 7]);
+}
+
+#[test]
+fn reading_truncated_input() {
+    let bytes = to_vec(value());
+    for capacity in [0, 1, 8, 2048] {
+        let truncated = &bytes[..bytes.len() - 1];
+        let mut sender = BufSender::with_capacity(capacity, ShortReader::new(truncated, 3));
+        assert!(sender.auto::<Value>().unwrap_err().is_eof());
+    }
+}
+
+#[test]
+fn writing_with_any_capacity() {
+    let expected = to_vec((value(), value()));
+    for capacity in [0, 1, 2, 3, 8, 1024, DEFAULT_BUFFER_SIZE] {
+        let mut receiver = BufReceiver::with_capacity(capacity, RecordingWriter::default());
+        receiver.auto(value()).unwrap();
+        receiver.auto(value()).unwrap();
+        let writer = receiver.into_inner().unwrap();
+        assert_eq!(writer.writes.concat(), expected, "{capacity}");
+    }
+}
+
+#[test]
+fn writing_large_buffers_directly() {
+    let mut receiver = BufReceiver::with_capacity(16, RecordingWriter::default());
+    receiver.accept_buffer(&[1, 2, 3, 4]).unwrap();
+    receiver.accept_buffer(&[5, 6, 7, 8]).unwrap();
+    assert!(receiver.get_ref().writes.is_empty());
+    // does not fit into the buffer, which is written first
+    receiver.accept_buffer(&[9; 10]).unwrap();
+    assert_eq!(receiver.get_ref().writes, [vec![1, 2, 3, 4, 5, 6, 7, 8]]);
+    // at least as large as the buffer, written directly
+    receiver.accept_buffer(&[0; 16]).unwrap();
+    receiver.accept(1).unwrap();
+    let writer = receiver.into_inner().unwrap();
+    assert_eq!(
+        writer.writes,
+        [
+            vec![1, 2, 3, 4, 5, 6, 7, 8],
+            vec![9; 10],
+            vec![0; 16],
+            vec![1]
+        ]
+    );
+}
+
+#[test]
+fn dropping_writes_the_buffer() {
+    let mut bytes = Vec::new();
+    {
+        let mut receiver = BufReceiver::with_capacity(64, &mut bytes);
+        receiver.auto(0x0102u16).unwrap();
+    }
+    assert_eq!(bytes, [1, 2]);
+}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use esde::*;
+
+/// decode `bytes` as `T`, expecting a [`ParseErrorKind::DuplicateKey`] error at the element `index` that starts at `offset`
+fn assert_duplicate<T: Deserialize<u8>>(bytes: &[u8], index: usize, offset: usize) {
+    let Err(Error::Parse(err)) = from_bytes::<T>(bytes) else {
+        panic!("expected a parse error");
+    };
+    assert!(matches!(err.kind(), ParseErrorKind::DuplicateKey), "{err}");
+    assert_eq!(err.path(), [PathSegment::Index(index)]);
+    assert_eq!(err.offset(), Some(offset));
+}
+
+#[test]
+fn maps_and_sets_round_trip() {
+    let map = BTreeMap::from([(1u8, String::from("one")), (2, String::from("two"))]);
+    assert_eq!(
+        from_bytes::<BTreeMap<u8, String>>(&to_vec(&map)).unwrap(),
+        map
+    );
+
+    let map: HashMap<_, _> = map.into_iter().collect();
+    assert_eq!(
+        from_bytes::<HashMap<u8, String>>(&to_vec(&map)).unwrap(),
+        map
+    );
+
+    let set = BTreeSet::from([3u16, 1, 2]);
+    assert_eq!(from_bytes::<BTreeSet<u16>>(&to_vec(&set)).unwrap(), set);
+
+    let set: HashSet<_> = set.into_iter().collect();
+    assert_eq!(from_bytes::<HashSet<u16>>(&to_vec(&set)).unwrap(), set);
+}
+
+#[test]
+fn duplicate_keys() {
+    // the same layout as a vector of (key, value) tuples, each taking up 2 bytes
+    let bytes = to_vec(vec![(1u8, 10u8), (2, 20), (1, 30)]);
+    assert_duplicate::<BTreeMap<u8, u8>>(&bytes, 2, 12);
+    assert_duplicate::<HashMap<u8, u8>>(&bytes, 2, 12);
+
+    let bytes = to_vec(vec![7u8, 7]);
+    assert_duplicate::<BTreeSet<u8>>(&bytes, 1, 9);
+    assert_duplicate::<HashSet<u8>>(&bytes, 1, 9);
+}
+
+#[test]
+fn duplicates_in_sequences_are_fine() {
+    let bytes = to_vec(vec![7u8, 7]);
+    assert_eq!(from_bytes::<Vec<u8>>(&bytes).unwrap(), [7, 7]);
+    let heap: std::collections::BinaryHeap<u8> = from_bytes(&bytes).unwrap();
+    assert_eq!(heap.into_vec(), [7, 7]);
+}
@@ -0,0 +1,35 @@
+//! helpers shared by the integration tests (not every test uses all of them)
+#![allow(dead_code)]
+
+use esde::*;
+
+/// the kind of a parse error, panicking on any other error
+pub fn parse_kind<E: std::error::Error>(err: &Error<E>) -> &ParseErrorKind {
+    err.as_parse().expect("expected a parse error").kind()
+}
+
+/// decode `bytes` as `T`, expecting a parse error
+pub fn parse_error<T: Deserialize<u8>>(bytes: &[u8]) -> ParseError {
+    match from_bytes::<T>(bytes) {
+        Err(Error::Parse(err)) => err,
+        Err(err) => panic!("expected a parse error, got {err}"),
+        Ok(_) => panic!("expected a parse error, got a value"),
+    }
+}
+
+/// the encoding of `len` as (fixed-width) length prefix
+pub fn length(len: u64) -> Vec<u8> {
+    len.to_be_bytes().to_vec()
+}
+
+/// write every value of `values` as a frame
+pub fn frames<T: Serialize<u8>>(values: impl IntoIterator<Item = T>, checksum: bool) -> Vec<u8> {
+    let mut writer = FramedWriter::new(VecReceiver::new());
+    if checksum {
+        writer = writer.with_checksum();
+    }
+    for value in values {
+        writer.send(value).unwrap();
+    }
+    writer.into_inner().into_inner()
+}
@@ -0,0 +1,118 @@
+use common::*;
+use esde::*;
+
+mod common;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    flag: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Outer {
+    name: String,
+    list: Vec<Inner>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Pair(u8, bool);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { width: u32, filled: bool },
+}
+
+#[test]
+fn round_trip() {
+    let outer = Outer {
+        name: String::from("outer"),
+        list: vec![Inner { flag: true }, Inner { flag: false }],
+    };
+    assert_eq!(from_bytes::<Outer>(&to_vec(&outer)).unwrap(), outer);
+    assert_eq!(
+        from_bytes::<Pair>(&to_vec(Pair(7, true))).unwrap(),
+        Pair(7, true)
+    );
+
+    let shapes = vec![
+        Shape::Empty,
+        Shape::Circle(3),
+        Shape::Rect {
+            width: 4,
+            filled: true,
+        },
+    ];
+    let bytes = to_vec(&shapes);
+    assert_eq!(bytes[8..10], [0, 1]);
+    assert_eq!(from_bytes::<Vec<Shape>>(&bytes).unwrap(), shapes);
+}
+
+#[test]
+fn nested_fields_are_in_the_path() {
+    let outer = Outer {
+        name: String::from("outer"),
+        list: vec![Inner { flag: true }, Inner { flag: false }],
+    };
+    let mut bytes = to_vec(&outer);
+    let last = bytes.len() - 1;
+    bytes[last] = 2;
+
+    let err = parse_error::<Outer>(&bytes);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(2)));
+    assert_eq!(
+        err.path(),
+        [
+            PathSegment::Field("list"),
+            PathSegment::Index(1),
+            PathSegment::Field("flag"),
+        ]
+    );
+    assert_eq!(err.offset(), Some(last));
+    assert!(err.to_string().contains("`list[1].flag`"), "{err}");
+
+    let err = parse_error::<Pair>(&[7, 2]);
+    assert_eq!(err.path(), [PathSegment::Field("1")]);
+}
+
+#[test]
+fn variants_are_in_the_path() {
+    let mut bytes = to_vec(Shape::Rect {
+        width: 4,
+        filled: true,
+    });
+    let last = bytes.len() - 1;
+    bytes[last] = 2;
+    let err = parse_error::<Shape>(&bytes);
+    assert_eq!(
+        err.path(),
+        [PathSegment::Variant("Rect"), PathSegment::Field("filled")]
+    );
+}
+
+#[test]
+fn unknown_variant() {
+    let err = parse_error::<Shape>(&[3]);
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::InvalidDiscriminant {
+            ty: "Shape",
+            discriminant: 3,
+        }
+    ));
+    assert_eq!(err.offset(), Some(0));
+
+    // a valid `Rect`, then an unknown variant
+    let mut bytes = to_vec(2usize);
+    bytes.extend([2, 0, 0, 0, 4, 1, 7]);
+    let err = parse_error::<Vec<Shape>>(&bytes);
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::InvalidDiscriminant {
+            discriminant: 7,
+            ..
+        }
+    ));
+    assert_eq!(err.path(), [PathSegment::Index(1)]);
+}
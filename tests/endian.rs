@@ -0,0 +1,87 @@
+use std::{convert::Infallible, fmt::Debug};
+
+use esde::*;
+
+fn config(endian: Endian) -> Config {
+    Config {
+        endian,
+        ..Config::DEFAULT
+    }
+}
+
+fn encode(value: impl Serialize<u8>, endian: Endian) -> Vec<u8> {
+    let mut receiver = Configured::new(VecReceiver::new(), config(endian));
+    receiver.auto(value).unwrap();
+    receiver.into_inner().into_inner()
+}
+
+fn decode<T: Deserialize<u8>>(bytes: &[u8], endian: Endian) -> Result<T, Error<Infallible>> {
+    let mut sender = Configured::new(SliceSender::new(bytes), config(endian));
+    let value = sender.auto()?;
+    sender.into_inner().finish()?;
+    Ok(value)
+}
+
+/// check that `value` is encoded as `big` (and the reverse in little endian) and decoded back
+fn assert_layout<T>(value: T, big: &[u8])
+where
+    T: Serialize<u8> + Deserialize<u8> + Copy + PartialEq + Debug,
+{
+    let little: Vec<u8> = big.iter().rev().copied().collect();
+    assert_eq!(encode(value, Endian::Big), big);
+    assert_eq!(encode(value, Endian::Little), little);
+    assert_eq!(decode::<T>(big, Endian::Big).unwrap(), value);
+    assert_eq!(decode::<T>(&little, Endian::Little).unwrap(), value);
+}
+
+#[test]
+fn integers() {
+    assert_layout(0x12u8, &[0x12]);
+    assert_layout(-2i8, &[0xfe]);
+    assert_layout(0x1234u16, &[0x12, 0x34]);
+    assert_layout(-2i16, &[0xff, 0xfe]);
+    assert_layout(0x1234_5678u32, &[0x12, 0x34, 0x56, 0x78]);
+    assert_layout(-2i32, &[0xff, 0xff, 0xff, 0xfe]);
+    assert_layout(
+        0x0102_0304_0506_0708u64,
+        &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+    );
+    assert_layout(-2i64, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]);
+    assert_layout(1u128 << 120, &(1u128 << 120).to_be_bytes());
+    assert_layout(i128::MIN + 1, &(i128::MIN + 1).to_be_bytes());
+    // `usize` and `isize` are stored as 64 bits
+    assert_layout(0x0102usize, &[0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+    assert_layout(-2isize, &(-2i64).to_be_bytes());
+    // chars are stored as `u32`
+    assert_layout('€', &[0x00, 0x00, 0x20, 0xac]);
+}
+
+#[test]
+fn floats() {
+    assert_layout(1.5f32, &[0x3f, 0xc0, 0x00, 0x00]);
+    assert_layout(-2.0f64, &[0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    assert_layout(f64::MIN_POSITIVE, &f64::MIN_POSITIVE.to_be_bytes());
+}
+
+#[test]
+fn little_endian_round_trip() {
+    let value = (
+        vec![1u16, 0x0203],
+        String::from("hi"),
+        Some(-1.25f32),
+        [7u32; 2],
+    );
+    let bytes = encode(value.clone(), Endian::Little);
+    // the length of the vector comes first, least significant byte first
+    assert_eq!(bytes[..8], [2, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(bytes[8..12], [1, 0, 3, 2]);
+    assert_eq!(bytes[12..14], [2, 0]);
+    assert_eq!(
+        decode::<(Vec<u16>, String, Option<f32>, [u32; 2])>(&bytes, Endian::Little).unwrap(),
+        value
+    );
+
+    // the byte order is not detected
+    let swapped = decode::<(Vec<u16>, String, Option<f32>, [u32; 2])>(&bytes, Endian::Big);
+    assert!(swapped.is_err());
+}
@@ -0,0 +1,140 @@
+use std::{convert::Infallible, error::Error as _};
+
+use common::*;
+use esde::*;
+
+mod common;
+
+#[test]
+fn errors_are_send_sync_static() {
+    fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+    assert_send_sync_static::<Error<std::io::Error>>();
+    assert_send_sync_static::<Error<Infallible>>();
+    assert_send_sync_static::<ParseError>();
+
+    // they can be passed on with `?` as boxed errors
+    fn read() -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(from_bytes(&[2])?)
+    }
+    assert!(read().is_err());
+}
+
+#[test]
+fn typed_kinds() {
+    let err = parse_error::<bool>(&[2]);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(2)));
+
+    let err = parse_error::<char>(&0xd800u32.to_be_bytes());
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidChar(0xd800)));
+
+    let err = parse_error::<String>(&[0, 0, 0, 0, 0, 0, 0, 1, 0xff]);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidUtf8(_)));
+    assert!(err.source().is_some());
+
+    let err = parse_error::<Result<u8, u8>>(&[2, 0]);
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::InvalidDiscriminant {
+            ty: "Result",
+            discriminant: 2,
+        }
+    ));
+
+    let err = parse_error::<std::num::NonZeroU8>(&[0]);
+    assert!(matches!(err.kind(), ParseErrorKind::UnexpectedZero { .. }));
+
+    let err = parse_error::<std::ffi::CString>(&to_vec(&b"ab\0c"[..]));
+    assert!(matches!(err.kind(), ParseErrorKind::InteriorNul(2)));
+
+    let err = parse_error::<u8>(&[1, 2]);
+    assert!(matches!(err.kind(), ParseErrorKind::TrailingItems(1)));
+}
+
+#[test]
+fn custom_errors() {
+    let err: Error<Infallible> = Error::make_parse(std::fmt::Error);
+    let err = err.into_parse().unwrap();
+    assert!(matches!(err.kind(), ParseErrorKind::Custom(_)));
+    assert!(err.source().unwrap().is::<std::fmt::Error>());
+
+    let err: Error<Infallible> = "something went wrong".into();
+    assert_eq!(err.as_parse().unwrap().to_string(), "something went wrong");
+}
+
+#[test]
+fn offset_and_path() {
+    let mut bytes = to_vec(vec![(1u8, false), (2, true)]);
+    let last = bytes.len() - 1;
+    bytes[last] = 7;
+
+    let err = parse_error::<Vec<(u8, bool)>>(&bytes);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(7)));
+    assert_eq!(err.offset(), Some(last));
+    assert_eq!(err.path(), [PathSegment::Index(1), PathSegment::Field("1")]);
+    assert_eq!(
+        err.to_string(),
+        format!("invalid bool 7 (expected 0 or 1) in `[1].1` at offset {last}")
+    );
+
+    let bytes = [1, 1, 2];
+    let err = parse_error::<Option<Result<u8, bool>>>(&bytes);
+    assert_eq!(
+        err.path(),
+        [PathSegment::Variant("Some"), PathSegment::Variant("Err")]
+    );
+    assert_eq!(err.offset(), Some(2));
+}
+
+#[test]
+fn offset_needs_position() {
+    let mut sender = io::reader(&[2u8][..]);
+    let err = sender.auto::<bool>().unwrap_err().into_parse().unwrap();
+    assert_eq!(err.offset(), Some(0));
+
+    // a sender that does not know its position
+    struct Bytes(std::vec::IntoIter<u8>);
+    impl Sender for Bytes {
+        type Item = u8;
+        type Error = Infallible;
+        fn get(&mut self) -> Result<u8, Error<Infallible>> {
+            self.0.next().ok_or(Error::EOF)
+        }
+    }
+    let mut sender = Bytes(vec![2].into_iter());
+    let err = sender.auto::<bool>().unwrap_err().into_parse().unwrap();
+    assert_eq!(err.offset(), None);
+}
+
+#[test]
+fn io_error_kinds() {
+    use std::io::{ErrorKind, Read};
+
+    /// read a `T` from `reader`, using `?` to convert the error
+    fn read<T: Deserialize<u8>>(reader: impl Read) -> std::io::Result<T> {
+        Ok(io::reader(reader).auto()?)
+    }
+
+    let err = read::<u32>(&[1, 2][..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.to_string(), Error::<std::io::Error>::EOF.to_string());
+
+    let err = read::<bool>(&[2][..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let source = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+    assert!(matches!(source.kind(), ParseErrorKind::InvalidBool(2)));
+    assert_eq!(source.offset(), Some(0));
+
+    // errors of the reader are passed on as they are
+    struct Failing;
+    impl Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::new(
+                ErrorKind::PermissionDenied,
+                "no access",
+            ))
+        }
+    }
+    let err = read::<u8>(Failing).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(err.to_string(), "no access");
+}
@@ -0,0 +1,87 @@
+use std::{
+    ffi::{CString, OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+use esde::*;
+
+#[test]
+fn unicode_os_strings_are_utf8() {
+    let string = OsString::from("päth/to/file");
+    let bytes = to_vec(&string);
+    assert_eq!(bytes, to_vec("päth/to/file"));
+    assert_eq!(from_bytes::<OsString>(&bytes).unwrap(), string);
+    assert_eq!(from_bytes::<String>(&bytes).unwrap(), "päth/to/file");
+
+    let path = Path::new("/tmp/file.txt");
+    let bytes = to_vec(path);
+    assert_eq!(bytes, to_vec("/tmp/file.txt"));
+    assert_eq!(from_bytes::<PathBuf>(&bytes).unwrap(), path);
+    assert_eq!(to_vec(OsStr::new("x")), to_vec("x"));
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_os_strings_round_trip_on_unix() {
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    let raw = b"caf\xe9/\xff\xfe".to_vec();
+    let string = OsString::from_vec(raw.clone());
+    let bytes = to_vec(&string);
+    // the raw bytes
+    assert_eq!(bytes, to_vec(raw.as_slice()));
+    assert_eq!(from_bytes::<OsString>(&bytes).unwrap(), string);
+    // not a valid `String`
+    assert!(matches!(
+        from_bytes::<String>(&bytes)
+            .unwrap_err()
+            .as_parse()
+            .unwrap()
+            .kind(),
+        ParseErrorKind::InvalidUtf8(_)
+    ));
+
+    let path = PathBuf::from(OsStr::from_bytes(&raw));
+    let bytes = to_vec(&path);
+    assert_eq!(from_bytes::<PathBuf>(&bytes).unwrap(), path);
+    assert_eq!(
+        from_bytes::<PathBuf>(&bytes)
+            .unwrap()
+            .as_os_str()
+            .as_bytes(),
+        raw
+    );
+}
+
+#[test]
+fn c_strings() {
+    let string = CString::new("hello").unwrap();
+    let bytes = to_vec(&string);
+    // the trailing nul byte is not saved
+    assert_eq!(bytes, to_vec("hello"));
+    assert_eq!(from_bytes::<CString>(&bytes).unwrap(), string);
+    assert_eq!(to_vec(c"hello"), bytes);
+
+    let empty = CString::default();
+    assert_eq!(from_bytes::<CString>(&to_vec(&empty)).unwrap(), empty);
+}
+
+#[test]
+fn c_strings_with_interior_nul() {
+    for (raw, position) in [(&b"\0"[..], 0), (b"ab\0c", 2), (b"abc\0", 3)] {
+        let err = from_bytes::<CString>(&to_vec(raw)).unwrap_err();
+        let err = err.as_parse().unwrap();
+        assert!(
+            matches!(err.kind(), ParseErrorKind::InteriorNul(p) if *p == position),
+            "{err}"
+        );
+        assert_eq!(
+            err.to_string(),
+            format!("nul byte at position {position} of a C string at offset 0")
+        );
+    }
+
+    // within a collection, the path points at the string
+    let err = from_bytes::<Vec<CString>>(&to_vec(vec![&b"ok"[..], b"n\0"])).unwrap_err();
+    assert_eq!(err.as_parse().unwrap().path(), [PathSegment::Index(1)]);
+}
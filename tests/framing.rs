@@ -0,0 +1,198 @@
+use std::convert::Infallible;
+
+use common::*;
+use esde::*;
+
+mod common;
+
+/// a reader that expects checksums
+fn reader(bytes: &[u8]) -> FramedReader<SliceSender<'_, u8>> {
+    FramedReader::new(SliceSender::new(bytes)).with_checksum()
+}
+
+#[test]
+fn round_trip() {
+    for checksum in [false, true] {
+        let bytes = frames(["one", "two", "three"], checksum);
+        let mut reader = FramedReader::new(SliceSender::new(&bytes));
+        if checksum {
+            reader = reader.with_checksum();
+        }
+        for expected in ["one", "two", "three"] {
+            assert_eq!(reader.receive::<String>().unwrap(), expected);
+        }
+        assert!(reader.receive::<String>().unwrap_err().is_eof());
+    }
+}
+
+#[test]
+fn layout() {
+    let bytes = frames([0x1234u16], true);
+    assert_eq!(bytes.len(), 8 + 4 + 2 + 4);
+    assert_eq!(bytes[..8], 2u64.to_be_bytes());
+    assert_eq!(bytes[12..14], [0x12, 0x34]);
+}
+
+#[test]
+fn round_trip_with_config() {
+    let config = Config {
+        int_encoding: IntEncoding::Varint,
+        ..Config::DEFAULT
+    };
+    let mut writer = FramedWriter::new(Configured::new(VecReceiver::new(), config)).with_checksum();
+    writer.send(300u64).unwrap();
+    writer.send(vec![1u32, 2, 3]).unwrap();
+    let bytes = writer.into_inner().into_inner().into_inner();
+    // the length of the first frame is a varint as well
+    assert_eq!(bytes[0], 2);
+
+    let sender = Configured::new(SliceSender::new(&bytes), config);
+    let mut reader = FramedReader::new(sender).with_checksum();
+    assert_eq!(reader.receive::<u64>().unwrap(), 300);
+    assert_eq!(reader.receive::<Vec<u32>>().unwrap(), [1, 2, 3]);
+    assert!(reader.receive::<u64>().unwrap_err().is_eof());
+}
+
+#[test]
+fn corrupted_payload_is_skipped() {
+    let mut bytes = frames([1u32, 2], true);
+    // the first byte of the first payload
+    bytes[12] ^= 0xff;
+    let mut reader = reader(&bytes);
+    let err = reader.receive::<u32>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::ChecksumMismatch { .. }
+    ));
+    assert_eq!(reader.receive::<u32>().unwrap(), 2);
+}
+
+#[test]
+fn corrupted_length_is_detected() {
+    let mut bytes = frames([1u32, 2], true);
+    bytes[7] = 3;
+    let err = reader(&bytes).receive::<u32>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::HeaderChecksumMismatch { .. }
+    ));
+    assert_eq!(err.as_parse().unwrap().offset(), Some(0));
+}
+
+#[test]
+fn frame_length_above_max() {
+    let mut bytes = length(DEFAULT_MAX_FRAME_LENGTH as u64 + 1);
+    bytes.push(0);
+    let mut reader = FramedReader::new(SliceSender::new(&bytes));
+    let err = reader.receive::<Vec<u8>>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_frame_length",
+            max: DEFAULT_MAX_FRAME_LENGTH,
+            ..
+        }
+    ));
+
+    let bytes = frames([[0u8; 16]], false);
+    let mut reader = FramedReader::new(SliceSender::new(&bytes)).with_max_frame_length(15);
+    let err = reader.receive::<[u8; 16]>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            max: 15,
+            value: 16,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn frame_length_above_remaining_input() {
+    let mut bytes = length(1024);
+    bytes.push(0);
+    let mut reader = FramedReader::new(SliceSender::new(&bytes));
+    assert!(reader.receive::<Vec<u8>>().unwrap_err().is_eof());
+}
+
+#[test]
+fn limits_apply_to_the_value() {
+    let limits = Limits {
+        max_length: Some(2),
+        ..Limits::NONE
+    };
+    let bytes = frames([vec![1u8, 2, 3], vec![4]], false);
+    let mut reader = FramedReader::new(SliceSender::new(&bytes)).with_limits(limits);
+    let err: Error<Infallible> = reader.receive::<Vec<u8>>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_length",
+            max: 2,
+            value: 3,
+        }
+    ));
+    // the frame was read completely nevertheless
+    assert_eq!(reader.receive::<Vec<u8>>().unwrap(), [4]);
+}
+
+#[test]
+fn limits_apply_to_every_value_on_its_own() {
+    // each value takes up exactly 10 bytes
+    let limits = Limits {
+        max_items: Some(10),
+        ..Limits::NONE
+    };
+    let bytes = frames([(1u64, 2u16); 5], true);
+    let mut limited = reader(&bytes).with_limits(limits);
+    for _ in 0..5 {
+        assert_eq!(limited.receive::<(u64, u16)>().unwrap(), (1, 2));
+    }
+
+    let bytes = frames([(1u64, 2u32)], true);
+    let err = reader(&bytes)
+        .with_limits(limits)
+        .receive::<(u64, u32)>()
+        .unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_items",
+            max: 10,
+            value: 12,
+        }
+    ));
+}
+
+#[test]
+fn limits_of_the_wrapped_sender_apply_to_the_stream() {
+    // every frame takes up 8 + 4 + 2 + 4 = 18 bytes
+    let limits = Limits {
+        max_items: Some(40),
+        ..Limits::NONE
+    };
+    let bytes = frames([1u16, 2, 3], true);
+    let mut reader =
+        FramedReader::new(Limited::new(SliceSender::new(&bytes), limits)).with_checksum();
+    assert_eq!(reader.receive::<u16>().unwrap(), 1);
+    assert_eq!(reader.receive::<u16>().unwrap(), 2);
+    let err = reader.receive::<u16>().unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_items",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn value_must_fill_the_frame() {
+    let bytes = frames([1u16, 2, 3], true);
+    let mut reader = reader(&bytes);
+    let err = reader.receive::<u8>().unwrap_err();
+    assert!(matches!(parse_kind(&err), ParseErrorKind::TrailingItems(1)));
+    let err = reader.receive::<u32>().unwrap_err();
+    assert!(matches!(parse_kind(&err), ParseErrorKind::IncompleteFrame));
+    assert_eq!(reader.receive::<u16>().unwrap(), 3);
+}
@@ -0,0 +1,185 @@
+use std::{collections::HashMap, convert::Infallible, marker::PhantomData, ops::RangeFull};
+
+use common::*;
+use esde::*;
+
+mod common;
+
+/// read a `T` from `bytes` through a [`Limited`] sender
+fn read_limited<T: Deserialize<u8>>(bytes: &[u8], limits: Limits) -> Result<T, Error<Infallible>> {
+    Limited::new(SliceSender::new(bytes), limits).auto()
+}
+
+#[test]
+fn vec_length_above_max_length() {
+    let limits = Limits {
+        max_length: Some(4),
+        ..Limits::NONE
+    };
+    let bytes = to_vec(vec![1u8, 2, 3, 4, 5]);
+    let err = read_limited::<Vec<u8>>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_length",
+            max: 4,
+            value: 5,
+        }
+    ));
+    assert_eq!(err.as_parse().unwrap().offset(), Some(0));
+
+    let bytes = to_vec(vec![1u8, 2, 3, 4]);
+    assert_eq!(
+        read_limited::<Vec<u8>>(&bytes, limits).unwrap(),
+        [1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn string_length_above_max_string_length() {
+    let limits = Limits {
+        max_string_length: Some(3),
+        ..Limits::NONE
+    };
+    let bytes = to_vec("four");
+    let err = read_limited::<String>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_string_length",
+            max: 3,
+            value: 4,
+        }
+    ));
+
+    // strings are not collections
+    let limits = Limits {
+        max_length: Some(3),
+        ..Limits::NONE
+    };
+    assert_eq!(read_limited::<String>(&bytes, limits).unwrap(), "four");
+}
+
+#[test]
+fn map_length_above_max_length() {
+    let limits = Limits {
+        max_length: Some(1),
+        ..Limits::NONE
+    };
+    let bytes = to_vec(HashMap::from([(1u8, 2u8), (3, 4)]));
+    let err = read_limited::<HashMap<u8, u8>>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_length",
+            max: 1,
+            value: 2,
+        }
+    ));
+}
+
+#[test]
+fn nested_length_error_has_path() {
+    let limits = Limits {
+        max_length: Some(2),
+        ..Limits::NONE
+    };
+    let bytes = to_vec(vec![vec![1u8], vec![1, 2, 3]]);
+    let err = read_limited::<Vec<Vec<u8>>>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded { value: 3, .. }
+    ));
+    assert_eq!(err.as_parse().unwrap().path(), [PathSegment::Index(1)]);
+}
+
+#[test]
+fn items_above_max_items() {
+    let limits = Limits {
+        max_items: Some(10),
+        ..Limits::NONE
+    };
+    let bytes = to_vec((1u64, 2u64));
+    let err = read_limited::<(u64, u64)>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_items",
+            max: 10,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn length_above_remaining_input() {
+    // byte lengths are rejected before any byte is read
+    let mut bytes = length(u64::MAX);
+    bytes.push(0);
+    assert!(from_bytes::<Vec<u8>>(&bytes).unwrap_err().is_eof());
+    assert!(from_bytes::<String>(&bytes).unwrap_err().is_eof());
+    // other collections run out of input while reading their elements
+    assert!(from_bytes::<HashMap<u8, u8>>(&bytes).unwrap_err().is_eof());
+}
+
+#[test]
+fn zero_sized_elements_round_trip() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Unit;
+
+    assert_eq!(
+        from_bytes::<Vec<()>>(&to_vec(vec![(); 3])).unwrap(),
+        [(); 3]
+    );
+    let phantoms = vec![PhantomData::<u8>; 3];
+    assert_eq!(
+        from_bytes::<Vec<PhantomData<u8>>>(&to_vec(&phantoms)).unwrap(),
+        phantoms
+    );
+    let units = vec![Unit, Unit, Unit];
+    let bytes = to_vec(&units);
+    assert_eq!(from_bytes::<Vec<Unit>>(&bytes).unwrap(), units);
+
+    // every sender accepts them
+    let mut sender = io::reader(bytes.as_slice());
+    assert_eq!(sender.auto::<Vec<Unit>>().unwrap(), units);
+    let mut writer = FramedWriter::new(VecReceiver::new());
+    writer.send(&units).unwrap();
+    let framed = writer.into_inner().into_inner();
+    let mut reader = FramedReader::new(SliceSender::new(&framed));
+    assert_eq!(reader.receive::<Vec<Unit>>().unwrap(), units);
+    let limits = Limits {
+        max_items: Some(8),
+        ..Limits::NONE
+    };
+    assert_eq!(
+        read_limited::<Vec<()>>(&length(100), limits).unwrap(),
+        [(); 100]
+    );
+}
+
+#[test]
+fn zero_sized_elements_with_huge_length() {
+    // only bounded by `max_length`
+    let limits = Limits {
+        max_length: Some(1024),
+        ..Limits::NONE
+    };
+    let bytes = length(u64::MAX);
+    let err = read_limited::<Vec<()>>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_length",
+            ..
+        }
+    ));
+    let err = read_limited::<Vec<RangeFull>>(&bytes, limits).unwrap_err();
+    assert!(matches!(
+        parse_kind(&err),
+        ParseErrorKind::LimitExceeded {
+            limit: "max_length",
+            ..
+        }
+    ));
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::Debug,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+};
+
+use common::*;
+use esde::*;
+
+mod common;
+
+fn round_trip<T: Serialize<u8> + Deserialize<u8> + Copy + PartialEq + Debug>(value: T) {
+    assert_eq!(from_bytes::<T>(&to_vec(value)).unwrap(), value);
+}
+
+const V4: Ipv4Addr = Ipv4Addr::new(192, 168, 0, 1);
+const V6: Ipv6Addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+
+#[test]
+fn layouts() {
+    assert_eq!(to_vec(V4), [192, 168, 0, 1]);
+    assert_eq!(to_vec(V6), V6.octets());
+    assert_eq!(to_vec(IpAddr::V4(V4)), [0, 192, 168, 0, 1]);
+    assert_eq!(to_vec(IpAddr::V6(V6))[0], 1);
+    assert_eq!(
+        to_vec(SocketAddrV4::new(V4, 0x1f90)),
+        [192, 168, 0, 1, 0x1f, 0x90]
+    );
+    assert_eq!(
+        to_vec(SocketAddrV6::new(V6, 80, 1, 2)),
+        to_vec((V6, 80u16, 1u32, 2u32))
+    );
+    assert_eq!(
+        to_vec(SocketAddr::V4(SocketAddrV4::new(V4, 80))),
+        [0, 192, 168, 0, 1, 0, 80]
+    );
+}
+
+#[test]
+fn round_trips() {
+    for ip in [Ipv4Addr::UNSPECIFIED, Ipv4Addr::BROADCAST, V4] {
+        round_trip(ip);
+        round_trip(IpAddr::V4(ip));
+        round_trip(SocketAddrV4::new(ip, u16::MAX));
+    }
+    for ip in [Ipv6Addr::UNSPECIFIED, Ipv6Addr::LOCALHOST, V6] {
+        round_trip(ip);
+        round_trip(IpAddr::V6(ip));
+        let addr = SocketAddrV6::new(ip, 443, 0x12345, 7);
+        round_trip(addr);
+        round_trip(SocketAddr::V6(addr));
+    }
+    round_trip(SocketAddr::V4(SocketAddrV4::new(V4, 8080)));
+}
+
+#[test]
+fn invalid_tags() {
+    let err = parse_error::<IpAddr>(&[2, 0, 0, 0, 0]);
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::InvalidDiscriminant {
+            ty: "IpAddr",
+            discriminant: 2,
+        }
+    ));
+    assert_eq!(err.offset(), Some(0));
+
+    let err = parse_error::<SocketAddr>(&[0xff]);
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::InvalidDiscriminant {
+            ty: "SocketAddr",
+            discriminant: 0xff,
+        }
+    ));
+}
+
+#[test]
+fn errors_name_the_variant() {
+    let config = Config {
+        int_encoding: IntEncoding::Varint,
+        ..Config::DEFAULT
+    };
+    // a port of 2^16 in varint encoding
+    let bytes = [0, 192, 168, 0, 1, 0x80, 0x80, 0x04];
+    let mut sender = Configured::new(SliceSender::new(&bytes), config);
+    let err = sender
+        .auto::<SocketAddr>()
+        .unwrap_err()
+        .into_parse()
+        .unwrap();
+    assert!(matches!(
+        err.kind(),
+        ParseErrorKind::IntegerOverflow { bits: 16 }
+    ));
+    // the port is read as the second field of a tuple
+    assert_eq!(
+        err.path(),
+        [PathSegment::Variant("V4"), PathSegment::Field("1")]
+    );
+
+    let mut bytes = vec![1];
+    bytes.extend(V6.octets());
+    bytes.extend([0x80, 0x80, 0x04]);
+    let mut sender = Configured::new(SliceSender::new(&bytes), config);
+    let err = sender
+        .auto::<SocketAddr>()
+        .unwrap_err()
+        .into_parse()
+        .unwrap();
+    assert_eq!(
+        err.path(),
+        [PathSegment::Variant("V6"), PathSegment::Field("1")]
+    );
+}
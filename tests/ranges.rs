@@ -0,0 +1,103 @@
+use std::{
+    fmt::Debug,
+    ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use common::*;
+use esde::*;
+
+mod common;
+
+/// check that `value` is saved as `layout` (owned and by reference) and read back
+fn assert_layout<T>(value: T, layout: impl Serialize<u8>)
+where
+    T: Serialize<u8> + SerializeRef<u8> + Deserialize<u8> + Clone + PartialEq + Debug,
+{
+    let bytes = to_vec(layout);
+    assert_eq!(to_vec(&value), bytes);
+    assert_eq!(to_vec(value.clone()), bytes);
+    assert_eq!(from_bytes::<T>(&bytes).unwrap(), value);
+}
+
+/// the same shape as [`Bound`], to compare with the derived layout
+#[derive(Serialize)]
+enum DerivedBound {
+    Included(u32),
+    Excluded(u32),
+    Unbounded,
+}
+
+#[test]
+fn ranges() {
+    assert_layout(1u16..300, (1u16, 300u16));
+    // empty ranges are saved as they are
+    assert_layout(Range { start: 5u8, end: 2 }, (5u8, 2u8));
+    assert_layout(-3i32..=3, (-3i32, 3i32));
+    assert_layout(String::from("a")..String::from("z"), ("a", "z"));
+    assert_layout(7u64.., 7u64);
+    assert_layout(..7u64, 7u64);
+    assert_layout(..=7u64, 7u64);
+    assert_layout(.., ());
+    assert!(to_vec(..).is_empty());
+
+    // an exhausted inclusive range is read back as a fresh one
+    let mut exhausted = 1u8..=1;
+    exhausted.next();
+    assert!(exhausted.is_empty());
+    let read = from_bytes::<RangeInclusive<u8>>(&to_vec(exhausted)).unwrap();
+    assert_eq!(read, 1..=1);
+    assert!(!read.is_empty());
+
+    // the types differ only in what they save
+    let bytes = to_vec(4u8..9);
+    assert_eq!(from_bytes::<RangeInclusive<u8>>(&bytes).unwrap(), 4..=9);
+    assert_eq!(
+        from_bytes::<(RangeFrom<u8>, RangeTo<u8>)>(&bytes).unwrap(),
+        (4.., ..9)
+    );
+    assert_eq!(
+        from_bytes::<(RangeFull, RangeToInclusive<u8>, Range<u8>)>(&[1, 2, 3]).unwrap(),
+        (.., ..=1, 2..3)
+    );
+}
+
+#[test]
+fn bounds() {
+    assert_layout(Bound::Included(3u32), DerivedBound::Included(3u32));
+    assert_layout(Bound::Excluded(3u32), DerivedBound::Excluded(3u32));
+    assert_layout(Bound::<u32>::Unbounded, DerivedBound::Unbounded);
+    assert_eq!(to_vec(Bound::Excluded(3u8)), [1, 3]);
+    assert_eq!(to_vec(Bound::<u8>::Unbounded), [2]);
+    assert_layout(
+        (Bound::Included(String::from("a")), Bound::<u8>::Unbounded),
+        ((0u8, "a"), 2u8),
+    );
+}
+
+#[test]
+fn invalid_bounds() {
+    for discriminant in [3, 0xff] {
+        let err = parse_error::<Bound<u8>>(&[discriminant, 0]);
+        assert!(
+            matches!(
+                err.kind(),
+                ParseErrorKind::InvalidDiscriminant { ty: "Bound", discriminant: d } if *d == discriminant
+            ),
+            "{err}"
+        );
+        assert_eq!(err.offset(), Some(0));
+    }
+
+    // errors in the value name the variant
+    let err = parse_error::<(u8, Bound<bool>)>(&[0, 1, 2]);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(2)));
+    assert_eq!(
+        err.path(),
+        [PathSegment::Field("1"), PathSegment::Variant("Excluded")]
+    );
+    assert_eq!(err.offset(), Some(2));
+
+    // and so do errors in ranges
+    let err = parse_error::<Range<bool>>(&[0, 5]);
+    assert_eq!(err.path(), [PathSegment::Field("end")]);
+}
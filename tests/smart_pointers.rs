@@ -0,0 +1,122 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use esde::*;
+
+/// check that `value` is saved just like `inner` (owned and by reference) and read back
+fn assert_transparent<T, U>(value: T, inner: U)
+where
+    T: Serialize<u8> + SerializeRef<u8> + Deserialize<u8> + PartialEq + Debug,
+    U: Serialize<u8>,
+{
+    let bytes = to_vec(inner);
+    assert_eq!(to_vec(&value), bytes);
+    assert_eq!(from_bytes::<T>(&bytes).unwrap(), value);
+    assert_eq!(to_vec(value), bytes);
+}
+
+#[test]
+fn boxes() {
+    assert_transparent(Box::new(7u32), 7u32);
+    assert_transparent(Box::new((1u8, String::from("x"))), (1u8, "x"));
+    assert_transparent(Box::new(Box::new(-1i16)), -1i16);
+
+    let slice: Box<[u16]> = vec![1, 2, 3].into_boxed_slice();
+    assert_transparent(slice, vec![1u16, 2, 3]);
+    let empty: Box<[u16]> = Box::new([]);
+    assert_transparent(empty, Vec::<u16>::new());
+    let string: Box<str> = "boxed".into();
+    assert_transparent(string, "boxed");
+}
+
+#[test]
+fn reference_counted() {
+    assert_transparent(Rc::new(7u32), 7u32);
+    assert_transparent(Arc::new(vec![1u8, 2]), vec![1u8, 2]);
+
+    let slice: Rc<[u16]> = Rc::from([1, 2, 3]);
+    assert_transparent(slice, [1u16, 2, 3].as_slice());
+    let string: Rc<str> = Rc::from("shared");
+    assert_transparent(string, "shared");
+    let slice: Arc<[u16]> = Arc::from([4, 5]);
+    assert_transparent(slice, [4u16, 5].as_slice());
+    let string: Arc<str> = Arc::from("");
+    assert_transparent(string, "");
+
+    // a shared value is saved once per reference
+    let shared = Rc::new(String::from("a"));
+    let bytes = to_vec(vec![Rc::clone(&shared), shared]);
+    assert_eq!(bytes, to_vec(vec!["a", "a"]));
+    let read = from_bytes::<Vec<Rc<String>>>(&bytes).unwrap();
+    assert!(!Rc::ptr_eq(&read[0], &read[1]));
+}
+
+#[test]
+fn cows() {
+    let borrowed: Cow<str> = Cow::Borrowed("cow");
+    let owned: Cow<str> = Cow::Owned(String::from("cow"));
+    assert_eq!(to_vec(&borrowed), to_vec(&owned));
+    assert_transparent(borrowed, "cow");
+
+    let slice: Cow<[u32]> = Cow::Borrowed(&[1, 2]);
+    assert_transparent(slice, vec![1u32, 2]);
+
+    // reading always gives an owned value
+    let read = from_bytes::<Cow<str>>(&to_vec("cow")).unwrap();
+    assert!(matches!(read, Cow::Owned(_)));
+}
+
+#[test]
+fn cells() {
+    assert_transparent(Cell::new(3u64), 3u64);
+    assert_transparent(RefCell::new(String::from("cell")), "cell");
+
+    let cell = RefCell::new(vec![1u8]);
+    cell.borrow_mut().push(2);
+    assert_eq!(to_vec(&cell), to_vec(vec![1u8, 2]));
+}
+
+#[test]
+fn locks() {
+    let bytes = to_vec((1u8, "lock"));
+
+    let mutex = Mutex::new((1u8, String::from("lock")));
+    assert_eq!(to_vec(&mutex), bytes);
+    assert_eq!(to_vec(mutex), bytes);
+    let read = from_bytes::<Mutex<(u8, String)>>(&bytes).unwrap();
+    assert_eq!(*read.lock().unwrap(), (1, String::from("lock")));
+
+    let lock = RwLock::new((1u8, String::from("lock")));
+    assert_eq!(to_vec(&lock), bytes);
+    assert_eq!(to_vec(lock), bytes);
+    let read = from_bytes::<RwLock<(u8, String)>>(&bytes).unwrap();
+    assert_eq!(*read.read().unwrap(), (1, String::from("lock")));
+}
+
+#[test]
+fn poisoned_locks() {
+    let mutex = Arc::new(Mutex::new(5u8));
+    let lock = Arc::new(RwLock::new(6u8));
+    let (m, l) = (Arc::clone(&mutex), Arc::clone(&lock));
+    std::thread::spawn(move || {
+        let _mutex = m.lock().unwrap();
+        let _lock = l.write().unwrap();
+        panic!("poisoning the locks");
+    })
+    .join()
+    .unwrap_err();
+    assert!(mutex.is_poisoned() && lock.is_poisoned());
+
+    // the value is saved anyway
+    assert_eq!(to_vec(&*mutex), [5]);
+    assert_eq!(to_vec(&*lock), [6]);
+    let mutex = Arc::into_inner(mutex).unwrap();
+    let lock = Arc::into_inner(lock).unwrap();
+    assert_eq!(to_vec(mutex), [5]);
+    assert_eq!(to_vec(lock), [6]);
+}
@@ -0,0 +1,89 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use esde::*;
+
+/// the encoding of a pair of seconds and nanoseconds
+fn encode(secs: impl Serialize<u8>, nanos: u32) -> Vec<u8> {
+    to_vec((secs, nanos))
+}
+
+#[test]
+fn durations() {
+    let duration = Duration::new(3, 250_000_000);
+    assert_eq!(to_vec(duration), encode(3u64, 250_000_000));
+    for duration in [Duration::ZERO, duration, Duration::MAX] {
+        assert_eq!(from_bytes::<Duration>(&to_vec(duration)).unwrap(), duration);
+    }
+}
+
+#[test]
+fn times_around_the_epoch() {
+    let half = Duration::from_millis(500);
+    // half a second before the epoch is a whole second before it plus half a second
+    let before = UNIX_EPOCH - half;
+    assert_eq!(to_vec(before), encode(-1i64, 500_000_000));
+    assert_eq!(
+        to_vec(UNIX_EPOCH - Duration::from_secs(2)),
+        encode(-2i64, 0)
+    );
+    assert_eq!(to_vec(UNIX_EPOCH + half), encode(0i64, 500_000_000));
+
+    for time in [
+        UNIX_EPOCH,
+        before,
+        UNIX_EPOCH - Duration::new(1_000_000, 1),
+        UNIX_EPOCH + Duration::new(1_000_000, 999_999_999),
+        SystemTime::now(),
+    ] {
+        assert_eq!(from_bytes::<SystemTime>(&to_vec(time)).unwrap(), time);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn extreme_times() {
+    // the seconds of this point in time are exactly `i64::MIN`
+    let earliest = UNIX_EPOCH - Duration::from_secs(1 << 63);
+    assert_eq!(to_vec(earliest), encode(i64::MIN, 0));
+    assert_eq!(
+        from_bytes::<SystemTime>(&to_vec(earliest)).unwrap(),
+        earliest
+    );
+
+    let almost = UNIX_EPOCH - Duration::new((1 << 63) - 1, 1);
+    assert_eq!(to_vec(almost), encode(i64::MIN, 999_999_999));
+    assert_eq!(from_bytes::<SystemTime>(&to_vec(almost)).unwrap(), almost);
+
+    let latest = UNIX_EPOCH + Duration::new(i64::MAX as u64, 999_999_999);
+    assert_eq!(to_vec(latest), encode(i64::MAX, 999_999_999));
+    assert_eq!(from_bytes::<SystemTime>(&to_vec(latest)).unwrap(), latest);
+}
+
+#[test]
+fn invalid_nanos() {
+    for nanos in [1_000_000_000, u32::MAX] {
+        let err = from_bytes::<Duration>(&encode(0u64, nanos)).unwrap_err();
+        assert!(matches!(
+            err.as_parse().unwrap().kind(),
+            ParseErrorKind::InvalidNanos(n) if *n == nanos
+        ));
+        assert_eq!(err.as_parse().unwrap().offset(), Some(8));
+
+        let err = from_bytes::<SystemTime>(&encode(-1i64, nanos)).unwrap_err();
+        assert!(matches!(
+            err.as_parse().unwrap().kind(),
+            ParseErrorKind::InvalidNanos(n) if *n == nanos
+        ));
+    }
+}
+
+#[cfg(windows)]
+#[test]
+fn time_out_of_range() {
+    // windows cannot represent points in time before 1601
+    let err = from_bytes::<SystemTime>(&encode(i64::MIN, 0)).unwrap_err();
+    assert!(matches!(
+        err.as_parse().unwrap().kind(),
+        ParseErrorKind::TimeOutOfRange
+    ));
+}
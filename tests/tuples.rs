@@ -0,0 +1,169 @@
+use common::*;
+use esde::*;
+
+mod common;
+
+/// a tuple of the largest supported arity
+type Sixteen = (
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    bool,
+    char,
+    String,
+    Vec<u8>,
+    Option<u8>,
+    f32,
+    (),
+    [u8; 2],
+);
+
+/// [`Sixteen`] with the string and the vector borrowed
+type SixteenBorrowed<'de> = (
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    bool,
+    char,
+    &'de str,
+    &'de [u8],
+    Option<u8>,
+    f32,
+    (),
+    [u8; 2],
+);
+
+fn sixteen() -> Sixteen {
+    (
+        1,
+        2,
+        3,
+        4,
+        -5,
+        -6,
+        -7,
+        -8,
+        true,
+        'x',
+        String::from("ten"),
+        vec![11, 11],
+        Some(12),
+        13.5,
+        (),
+        [15, 15],
+    )
+}
+
+#[test]
+fn round_trip_of_the_largest_arity() {
+    let bytes = to_vec(sixteen());
+    // the elements one after another, without any framing
+    let halves = (
+        to_vec((1u8, 2u16, 3u32, 4u64, -5i8, -6i16, -7i32, -8i64)),
+        to_vec((
+            true,
+            'x',
+            "ten",
+            vec![11u8, 11],
+            Some(12u8),
+            13.5f32,
+            (),
+            [15u8, 15],
+        )),
+    );
+    assert_eq!(bytes, [halves.0, halves.1].concat());
+
+    // the standard library compares tuples of at most 12 elements, so compare the halves
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) = from_bytes::<Sixteen>(&bytes).unwrap();
+    assert_eq!((a, b, c, d, e, f, g, h), (1, 2, 3, 4, -5, -6, -7, -8));
+    assert_eq!(
+        (i, j, k, l, m, n, o, p),
+        (
+            true,
+            'x',
+            String::from("ten"),
+            vec![11, 11],
+            Some(12),
+            13.5,
+            (),
+            [15, 15]
+        )
+    );
+
+    let borrowed = {
+        let mut sender = SliceSender::new(&bytes);
+        let value: SixteenBorrowed = sender.auto_borrow().unwrap();
+        sender.finish().unwrap();
+        value
+    };
+    assert_eq!(borrowed.10, "ten");
+    assert_eq!(borrowed.11, [11, 11]);
+    assert_eq!(borrowed.15, [15, 15]);
+}
+
+#[test]
+fn errors_name_the_element() {
+    // the bool is the ninth element
+    let mut bytes = to_vec(sixteen());
+    let offset = 1 + 2 + 4 + 8 + 1 + 2 + 4 + 8;
+    bytes[offset] = 2;
+    let err = parse_error::<Sixteen>(&bytes);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(2)));
+    assert_eq!(err.path(), [PathSegment::Field("8")]);
+    assert_eq!(err.offset(), Some(offset));
+
+    // the last element
+    let err = parse_error::<(
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        bool,
+    )>(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(3)));
+    assert_eq!(err.path(), [PathSegment::Field("15")]);
+    assert_eq!(
+        err.to_string(),
+        "invalid bool 3 (expected 0 or 1) in `15` at offset 15"
+    );
+}
+
+#[test]
+fn errors_in_nested_tuples() {
+    let bytes = to_vec((1u8, (2u8, (3u8, 4u8))));
+    let err = parse_error::<(u8, (u8, (u8, bool)))>(&bytes);
+    assert!(matches!(err.kind(), ParseErrorKind::InvalidBool(4)));
+    assert_eq!(
+        err.path(),
+        [
+            PathSegment::Field("1"),
+            PathSegment::Field("1"),
+            PathSegment::Field("1")
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid bool 4 (expected 0 or 1) in `1.1.1` at offset 3"
+    );
+}
@@ -0,0 +1,104 @@
+use std::{convert::Infallible, fmt::Debug};
+
+use esde::*;
+
+const VARINT: Config = Config {
+    int_encoding: IntEncoding::Varint,
+    ..Config::DEFAULT
+};
+
+fn encode(value: impl Serialize<u8>) -> Vec<u8> {
+    let mut receiver = Configured::new(VecReceiver::new(), VARINT);
+    receiver.auto(value).unwrap();
+    receiver.into_inner().into_inner()
+}
+
+fn decode<T: Deserialize<u8>>(bytes: &[u8]) -> Result<T, Error<Infallible>> {
+    let mut sender = Configured::new(SliceSender::new(bytes), VARINT);
+    let value = sender.auto()?;
+    sender.into_inner().finish()?;
+    Ok(value)
+}
+
+fn round_trip<T: Serialize<u8> + Deserialize<u8> + Copy + PartialEq + Debug>(values: &[T]) {
+    for &value in values {
+        assert_eq!(decode::<T>(&encode(value)).unwrap(), value);
+    }
+}
+
+/// the `bits` of an [`ParseErrorKind::IntegerOverflow`] error, panicking on any other error
+fn overflow_bits(err: Error<Infallible>) -> u32 {
+    match err.as_parse().map(ParseError::kind) {
+        Some(ParseErrorKind::IntegerOverflow { bits }) => *bits,
+        _ => panic!("expected an integer overflow, got {err}"),
+    }
+}
+
+#[test]
+fn encodings() {
+    assert_eq!(encode(0u32), [0]);
+    assert_eq!(encode(127u32), [0x7f]);
+    assert_eq!(encode(128u32), [0x80, 0x01]);
+    assert_eq!(encode(300u64), [0xac, 0x02]);
+    assert_eq!(encode(0i32), [0]);
+    assert_eq!(encode(-1i32), [1]);
+    assert_eq!(encode(1i32), [2]);
+    assert_eq!(encode(-64i64), [0x7f]);
+    assert_eq!(encode(u64::MAX).len(), 10);
+    assert_eq!(encode(u128::MAX).len(), 19);
+    // single bytes are not affected
+    assert_eq!(encode(200u8), [200]);
+    assert_eq!(encode(-1i8), [0xff]);
+    // lengths use varints as well
+    assert_eq!(encode("hi"), [2, b'h', b'i']);
+}
+
+#[test]
+fn extremes_round_trip() {
+    round_trip(&[0u16, 1, 127, 128, u16::MAX]);
+    round_trip(&[0u32, 1, 127, 128, u32::MAX]);
+    round_trip(&[0u64, 1, 127, 128, u64::MAX]);
+    round_trip(&[0u128, 1, 127, 128, u128::MAX]);
+    round_trip(&[0usize, 1, 127, 128, usize::MAX]);
+    round_trip(&[0i16, -1, 1, i16::MIN, i16::MAX]);
+    round_trip(&[0i32, -1, 1, i32::MIN, i32::MAX]);
+    round_trip(&[0i64, -1, 1, i64::MIN, i64::MAX]);
+    round_trip(&[0i128, -1, 1, i128::MIN, i128::MAX]);
+    round_trip(&[0isize, -1, 1, isize::MIN, isize::MAX]);
+}
+
+#[test]
+fn value_too_large() {
+    // 2^16 does not fit into an u16
+    assert_eq!(
+        overflow_bits(decode::<u16>(&[0x80, 0x80, 0x04]).unwrap_err()),
+        16
+    );
+    // u32::MAX + 1
+    let bytes = encode(u64::from(u32::MAX) + 1);
+    assert_eq!(overflow_bits(decode::<u32>(&bytes).unwrap_err()), 32);
+    // the last byte of an u64 may only carry a single bit
+    let mut bytes = vec![0xff; 9];
+    bytes.push(0x02);
+    assert_eq!(overflow_bits(decode::<u64>(&bytes).unwrap_err()), 64);
+    // a length larger than u64::MAX
+    let bytes = encode(u128::from(u64::MAX) + 1);
+    assert_eq!(overflow_bits(decode::<Vec<u8>>(&bytes).unwrap_err()), 64);
+}
+
+#[test]
+fn too_many_bytes() {
+    // zeros with continuation bits never end
+    let mut bytes = vec![0x80; 5];
+    bytes.push(0);
+    assert_eq!(overflow_bits(decode::<u32>(&bytes).unwrap_err()), 32);
+    let bytes = [0x80; 64];
+    assert_eq!(overflow_bits(decode::<u128>(&bytes).unwrap_err()), 128);
+}
+
+#[test]
+fn truncated() {
+    assert!(decode::<u32>(&[]).unwrap_err().is_eof());
+    assert!(decode::<u32>(&[0x80]).unwrap_err().is_eof());
+    assert!(decode::<u64>(&[0xff, 0xff]).unwrap_err().is_eof());
+}